@@ -201,7 +201,6 @@ fn ask_question(question_no: u16, question: &dyn Question) -> Result<(), io::Err
                 return Ok(());
             }
             Some(answer) => {
-                let answer = answer;
                 match question.answer(&answer) {
                     Outcome::Incorrect => {
                         println!("Your answer isn't quite right. Try again!");
@@ -209,6 +208,9 @@ fn ask_question(question_no: u16, question: &dyn Question) -> Result<(), io::Err
                     Outcome::Invalid(err) => {
                         println!("There was a problem with your answer: {err}");
                     }
+                    Outcome::Unsimplified => {
+                        println!("That's right, but can you simplify your answer?");
+                    }
                     Outcome::Correct => {
                         println!("That's the right answer. Great work!");
                         return Ok(());
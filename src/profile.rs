@@ -34,7 +34,7 @@ impl Profile {
 }
 
 /// Obtains a 'slug' from the given string, comprising transliterated alphabetic ASCII characters.
-fn sanitise(s: &str) -> String {
+pub(crate) fn sanitise(s: &str) -> String {
     let transliterated = unidecode(s);
     transliterated
         .to_ascii_lowercase()
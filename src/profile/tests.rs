@@ -45,7 +45,7 @@ fn test_sanitised_first_name() {
         expected: &'static str,
     }
 
-    for case in vec![
+    for case in [
         Case {
             input: "Emil",
             expected: "emil",
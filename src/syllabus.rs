@@ -16,7 +16,6 @@ impl Syllabus {
     pub fn get_topic_names(&self) -> Vec<String> {
         self.courses
             .values()
-            .into_iter()
             .flat_map(Course::get_topic_names)
             .unique()
             .sorted()
@@ -35,7 +34,6 @@ impl Course {
     pub fn get_topic_names(&self) -> Vec<String> {
         self.modules
             .values()
-            .into_iter()
             .map(|topic| topic.topic_name())
             .unique()
             .sorted()
@@ -45,7 +43,12 @@ impl Course {
 
 pub mod presets {
     use super::{Course, Syllabus};
-    use crate::topic::{addition, subtraction, Module};
+    use crate::topic::{
+        addition, comparison, decimals, division, equations, expressions, fractions, geometry,
+        money, multiplication, number_theory, number_words, percentages, place_value, powers,
+        roman_numerals, rounding, sequences, statistics, subtraction, time, times_tables, units,
+        Module,
+    };
     use std::collections::HashMap;
 
     pub fn primary() -> Syllabus {
@@ -54,6 +57,7 @@ pub mod presets {
                 (String::from("arithmetics_1"), arithmetics_1()),
                 (String::from("arithmetics_2"), arithmetics_2()),
                 (String::from("arithmetics_3"), arithmetics_3()),
+                (String::from("data_1"), data_1()),
             ]),
         }
     }
@@ -69,6 +73,35 @@ pub mod presets {
                     String::from("subtraction_1"),
                     boxify(subtraction::presets::subtraction_1()),
                 ),
+                (
+                    String::from("place_value_1"),
+                    boxify(place_value::presets::place_value_1()),
+                ),
+                (
+                    String::from("multiplication_1"),
+                    boxify(multiplication::presets::multiplication_1()),
+                ),
+                (
+                    String::from("division_1"),
+                    boxify(division::presets::division_1()),
+                ),
+                (
+                    String::from("comparison_1"),
+                    boxify(comparison::presets::comparison_1()),
+                ),
+                (
+                    String::from("number_words_1"),
+                    boxify(number_words::presets::number_words_1()),
+                ),
+                (
+                    String::from("sequences_1"),
+                    boxify(sequences::presets::sequences_1()),
+                ),
+                (String::from("time_1"), boxify(time::presets::time_1())),
+                (
+                    String::from("times_tables_1"),
+                    boxify(times_tables::presets::times_tables_1()),
+                ),
             ]),
         }
     }
@@ -80,10 +113,89 @@ pub mod presets {
                     String::from("addition_2"),
                     boxify(addition::presets::addition_2()),
                 ),
+                (
+                    String::from("addition_5"),
+                    boxify(addition::presets::addition_5()),
+                ),
                 (
                     String::from("subtraction_2"),
                     boxify(subtraction::presets::subtraction_2()),
                 ),
+                (
+                    String::from("subtraction_5"),
+                    boxify(subtraction::presets::subtraction_5()),
+                ),
+                (
+                    String::from("place_value_2"),
+                    boxify(place_value::presets::place_value_2()),
+                ),
+                (
+                    String::from("multiplication_2"),
+                    boxify(multiplication::presets::multiplication_2()),
+                ),
+                (
+                    String::from("division_2"),
+                    boxify(division::presets::division_2()),
+                ),
+                (
+                    String::from("comparison_2"),
+                    boxify(comparison::presets::comparison_2()),
+                ),
+                (
+                    String::from("equations_1"),
+                    boxify(equations::presets::equations_1()),
+                ),
+                (
+                    String::from("expressions_1"),
+                    boxify(expressions::presets::expressions_1()),
+                ),
+                (String::from("money_1"), boxify(money::presets::money_1())),
+                (String::from("units_1"), boxify(units::presets::units_1())),
+                (
+                    String::from("geometry_1"),
+                    boxify(geometry::presets::geometry_1()),
+                ),
+                (
+                    String::from("number_theory_1"),
+                    boxify(number_theory::presets::number_theory_1()),
+                ),
+                (
+                    String::from("number_words_2"),
+                    boxify(number_words::presets::number_words_2()),
+                ),
+                (
+                    String::from("percentages_1"),
+                    boxify(percentages::presets::percentages_1()),
+                ),
+                (
+                    String::from("fractions_1"),
+                    boxify(fractions::presets::fractions_1()),
+                ),
+                (
+                    String::from("decimals_1"),
+                    boxify(decimals::presets::decimals_1()),
+                ),
+                (
+                    String::from("powers_1"),
+                    boxify(powers::presets::powers_1()),
+                ),
+                (
+                    String::from("roman_numerals_1"),
+                    boxify(roman_numerals::presets::roman_numerals_1()),
+                ),
+                (
+                    String::from("rounding_1"),
+                    boxify(rounding::presets::rounding_1()),
+                ),
+                (
+                    String::from("sequences_2"),
+                    boxify(sequences::presets::sequences_2()),
+                ),
+                (String::from("time_2"), boxify(time::presets::time_2())),
+                (
+                    String::from("times_tables_2"),
+                    boxify(times_tables::presets::times_tables_2()),
+                ),
             ]),
         }
     }
@@ -95,10 +207,91 @@ pub mod presets {
                     String::from("addition_3"),
                     boxify(addition::presets::addition_3()),
                 ),
+                (
+                    String::from("addition_4"),
+                    boxify(addition::presets::addition_4()),
+                ),
+                (
+                    String::from("addition_6"),
+                    boxify(addition::presets::addition_6()),
+                ),
                 (
                     String::from("subtraction_3"),
                     boxify(subtraction::presets::subtraction_3()),
                 ),
+                (
+                    String::from("subtraction_4"),
+                    boxify(subtraction::presets::subtraction_4()),
+                ),
+                (
+                    String::from("multiplication_3"),
+                    boxify(multiplication::presets::multiplication_3()),
+                ),
+                (
+                    String::from("division_3"),
+                    boxify(division::presets::division_3()),
+                ),
+                (
+                    String::from("comparison_3"),
+                    boxify(comparison::presets::comparison_3()),
+                ),
+                (
+                    String::from("equations_2"),
+                    boxify(equations::presets::equations_2()),
+                ),
+                (
+                    String::from("expressions_2"),
+                    boxify(expressions::presets::expressions_2()),
+                ),
+                (String::from("money_2"), boxify(money::presets::money_2())),
+                (String::from("units_2"), boxify(units::presets::units_2())),
+                (
+                    String::from("geometry_2"),
+                    boxify(geometry::presets::geometry_2()),
+                ),
+                (
+                    String::from("number_theory_2"),
+                    boxify(number_theory::presets::number_theory_2()),
+                ),
+                (
+                    String::from("percentages_2"),
+                    boxify(percentages::presets::percentages_2()),
+                ),
+                (
+                    String::from("fractions_2"),
+                    boxify(fractions::presets::fractions_2()),
+                ),
+                (
+                    String::from("decimals_2"),
+                    boxify(decimals::presets::decimals_2()),
+                ),
+                (
+                    String::from("powers_2"),
+                    boxify(powers::presets::powers_2()),
+                ),
+                (
+                    String::from("roman_numerals_2"),
+                    boxify(roman_numerals::presets::roman_numerals_2()),
+                ),
+                (
+                    String::from("rounding_2"),
+                    boxify(rounding::presets::rounding_2()),
+                ),
+            ]),
+        }
+    }
+
+    fn data_1() -> Course {
+        Course {
+            modules: HashMap::from([
+                (
+                    String::from("statistics_1"),
+                    boxify(statistics::presets::statistics_1()),
+                ),
+                (
+                    String::from("statistics_2"),
+                    boxify(statistics::presets::statistics_2()),
+                ),
             ]),
         }
     }
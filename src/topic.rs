@@ -1,9 +1,32 @@
 //! Definition of modules and questions.
 
 pub mod addition;
+pub mod comparison;
+pub mod decimals;
+pub mod division;
+pub mod equations;
+pub mod expressions;
+pub mod fractions;
+pub mod geometry;
+pub mod money;
+pub mod multiplication;
+pub mod number_theory;
+pub mod number_words;
+pub mod percentages;
+pub mod place_value;
+pub mod powers;
+pub mod regrouping;
+pub mod roman_numerals;
+pub mod rounding;
+pub mod sequences;
+pub mod statistics;
 pub mod subtraction;
+pub mod time;
+pub mod times_tables;
+pub mod units;
 
 use std::fmt::Display;
+use std::ops::{Range, Rem};
 use tinyrand::{RandRange};
 
 /// An unbounded stream of questions on a particular topic. Topics such as addition may be
@@ -23,11 +46,73 @@ pub trait Question: Display {
     fn answer(&self, answer: &str) -> Outcome;
 }
 
+/// The part of an equation that is hidden from the student and must be supplied as the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unknown {
+    /// The result is hidden; e.g., `3 + 7 = ?`.
+    Result,
+
+    /// One of the operands, chosen at random, is hidden; e.g., `3 + ? = 10`.
+    Operand,
+
+    /// Either one of the operands or the result, chosen at random, is hidden.
+    Any,
+}
+
+impl Unknown {
+    /// Picks the position of the hidden term in an equation with the given number of operands.
+    /// Operands are numbered from zero; the result takes the position after the last operand.
+    pub(crate) fn position(self, operands: u32, rand: &mut dyn RandRange<u32>) -> u32 {
+        match self {
+            Unknown::Result => operands,
+            Unknown::Operand => rand.next_range(0..operands),
+            Unknown::Any => rand.next_range(0..operands + 1),
+        }
+    }
+}
+
+/// Draws a signed integer from the given range, which must not be empty.
+pub(crate) fn next_signed(rand: &mut dyn RandRange<u32>, range: Range<i32>) -> i32 {
+    let span = u32::try_from(i64::from(range.end) - i64::from(range.start)).unwrap();
+    let offset = i64::from(rand.next_range(0..span));
+    i32::try_from(i64::from(range.start) + offset).unwrap()
+}
+
+/// Picks an item from a non-empty list, without consuming a random number if there is only one.
+pub(crate) fn choose<'a, T>(items: &'a [T], rand: &mut dyn RandRange<u32>) -> &'a T {
+    if items.len() == 1 {
+        &items[0]
+    } else {
+        &items[rand.next_range(0..u32::try_from(items.len()).unwrap()) as usize]
+    }
+}
+
+/// The greatest common divisor of two numbers.
+pub(crate) fn gcd<T: Copy + PartialEq + Rem<Output = T> + From<u8>>(mut a: T, mut b: T) -> T {
+    while b != T::from(0) {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Formats an operand, enclosing it in brackets if it is negative; e.g., `5 – (-3)`.
+pub(crate) fn format_operand(operand: i32) -> String {
+    if operand < 0 {
+        format!("({operand})")
+    } else {
+        operand.to_string()
+    }
+}
+
 /// The outcome of answering a question.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Outcome {
     Incorrect,
     Invalid(String),
+
+    /// The answer has the correct value but is not in its simplest form; e.g., `6/8` instead
+    /// of `3/4`.
+    Unsimplified,
     Correct
 }
 
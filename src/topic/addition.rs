@@ -1,6 +1,7 @@
 //! Questions on addition.
 
-use crate::topic::{Outcome, Question, Module};
+use crate::topic::regrouping::{Operation, Order, PairSampler, Regrouping};
+use crate::topic::{format_operand, next_signed, Module, Outcome, Question, Unknown};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use tinyrand::RandRange;
@@ -8,15 +9,31 @@ use tinyrand::RandRange;
 /// The addition module.
 pub struct Addition {
     config: Config,
+
+    /// Generates the operands when [`Config::carries`] is constrained.
+    sampler: Option<PairSampler>,
 }
 
 /// Configuration for [`Addition`].
 pub struct Config {
-    /// The smallest number that will be asked.
-    pub min_val: u32,
+    /// The smallest number that will be asked. May be negative.
+    pub min_val: i32,
 
     /// The largest number that will be asked.
-    pub max_val: u32,
+    pub max_val: i32,
+
+    /// The smallest number of addends in a sum.
+    pub min_addends: u32,
+
+    /// The largest number of addends in a sum.
+    pub max_addends: u32,
+
+    /// The part of the sum that the student must find.
+    pub unknown: Unknown,
+
+    /// The number of carries that the sum requires when added in columns. Carries may only be
+    /// constrained for sums of two non-negative addends.
+    pub carries: Regrouping,
 }
 
 impl Config {
@@ -25,14 +42,51 @@ impl Config {
     /// # Errors
     /// If the config is invalid.
     pub fn validate(&self) -> Result<(), String> {
-        const MAX_MAX_VAL: u32 = u32::MAX << 1;
+        self.validate_and_sample().map(|_| ())
+    }
+
+    /// Validates the config, returning the sampler for constrained carries, if one is needed.
+    fn validate_and_sample(&self) -> Result<Option<PairSampler>, String> {
         if self.min_val >= self.max_val {
             return Err("min_val must be less than max_val".into());
         }
-        if self.max_val > MAX_MAX_VAL {
-            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        if self.min_addends < 2 {
+            return Err("min_addends must be at least 2".into());
+        }
+        if self.min_addends > self.max_addends {
+            return Err("min_addends cannot exceed max_addends".into());
+        }
+        let magnitude = self
+            .min_val
+            .unsigned_abs()
+            .max((self.max_val - 1).unsigned_abs());
+        if u64::from(magnitude) * u64::from(self.max_addends) > i32::MAX as u64 {
+            return Err(format!(
+                "the sum of max_addends numbers between min_val and max_val cannot exceed {} in magnitude",
+                i32::MAX
+            ));
+        }
+        if self.carries == Regrouping::Any {
+            return Ok(None);
+        }
+        if self.max_addends > 2 {
+            return Err("carries can only be constrained for sums of two addends".into());
         }
-        Ok(())
+        let Ok(min_val) = u32::try_from(self.min_val) else {
+            return Err("carries can only be constrained for non-negative addends".into());
+        };
+        let operands = min_val..=u32::try_from(self.max_val - 1).unwrap();
+        let sampler = PairSampler::new(
+            Operation::Add,
+            Order::Unordered,
+            operands.clone(),
+            operands,
+            self.carries,
+        );
+        if sampler.total() == 0 {
+            return Err("no sums between min_val and max_val satisfy the carries constraint".into());
+        }
+        Ok(Some(sampler))
     }
 }
 
@@ -40,8 +94,8 @@ impl TryFrom<Config> for Addition {
     type Error = String;
 
     fn try_from(config: Config) -> Result<Self, Self::Error> {
-        config.validate()?;
-        Ok(Self { config })
+        let sampler = config.validate_and_sample()?;
+        Ok(Self { config, sampler })
     }
 }
 
@@ -51,29 +105,88 @@ impl Module for Addition {
     }
 
     fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
-        let lhs = rand.next_range(self.config.min_val..self.config.max_val);
-        let rhs = rand.next_range(self.config.min_val..self.config.max_val);
-        Box::new(Sum { lhs, rhs })
+        let addends = match &self.sampler {
+            None => {
+                let count = if self.config.min_addends == self.config.max_addends {
+                    self.config.min_addends
+                } else {
+                    rand.next_range(self.config.min_addends..self.config.max_addends + 1)
+                };
+                (0..count)
+                    .map(|_| next_signed(rand, self.config.min_val..self.config.max_val))
+                    .collect::<Vec<_>>()
+            }
+            Some(sampler) => {
+                let (lhs, rhs) = sampler.sample(rand);
+                vec![i32::try_from(lhs).unwrap(), i32::try_from(rhs).unwrap()]
+            }
+        };
+        let unknown = self
+            .config
+            .unknown
+            .position(u32::try_from(addends.len()).unwrap(), rand);
+        Box::new(Sum {
+            addends,
+            unknown,
+            signed: self.config.min_val < 0,
+        })
     }
 }
 
 struct Sum {
-    lhs: u32,
-    rhs: u32,
+    addends: Vec<i32>,
+
+    /// The position of the hidden term: the index of one of the `addends`, or the number of
+    /// addends for the sum itself.
+    unknown: u32,
+
+    /// Whether the addends may be negative, in which case so may the answer.
+    signed: bool,
+}
+
+impl Sum {
+    fn terms(&self) -> Vec<i32> {
+        let mut terms = self.addends.clone();
+        terms.push(self.addends.iter().sum());
+        terms
+    }
 }
 
 impl Display for Sum {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Can you add these two numbers for me.")?;
-        write!(f, "{} + {} = ?", self.lhs, self.rhs)
+        let terms = self
+            .terms()
+            .iter()
+            .enumerate()
+            .map(|(position, term)| {
+                if position == self.unknown as usize {
+                    String::from("?")
+                } else if position == self.addends.len() {
+                    term.to_string()
+                } else {
+                    format_operand(*term)
+                }
+            })
+            .collect::<Vec<_>>();
+        if self.unknown as usize == self.addends.len() {
+            if self.addends.len() == 2 {
+                writeln!(f, "Can you add these two numbers for me.")?;
+            } else {
+                writeln!(f, "Can you add these numbers for me.")?;
+            }
+        } else {
+            writeln!(f, "Can you find the missing number for me.")?;
+        }
+        let (sum, addends) = terms.split_last().unwrap();
+        write!(f, "{} = {sum}", addends.join(" + "))
     }
 }
 
 impl Question for Sum {
     fn answer(&self, answer: &str) -> Outcome {
-        match parse(answer) {
+        match parse(answer, self.signed) {
             Ok(answer) => {
-                let expected = self.lhs + self.rhs;
+                let expected = self.terms()[self.unknown as usize];
                 if answer == expected {
                     Outcome::Correct
                 } else {
@@ -85,18 +198,31 @@ impl Question for Sum {
     }
 }
 
-fn parse(answer: &str) -> Result<u32, String> {
-    u32::from_str(answer)
-        .map_err(|_| format!("'{answer}' does not appear to be a valid natural number"))
+fn parse(answer: &str, signed: bool) -> Result<i32, String> {
+    if signed {
+        i32::from_str(answer)
+            .map_err(|_| format!("'{answer}' does not appear to be a valid integer"))
+    } else {
+        u32::from_str(answer)
+            .ok()
+            .and_then(|answer| i32::try_from(answer).ok())
+            .ok_or_else(|| format!("'{answer}' does not appear to be a valid natural number"))
+    }
 }
 
 pub mod presets {
     use super::{Addition, Config};
+    use crate::topic::regrouping::Regrouping;
+    use crate::topic::Unknown;
 
     pub fn addition_1() -> Addition {
         Config {
             min_val: 0,
             max_val: 10,
+            min_addends: 2,
+            max_addends: 2,
+            unknown: Unknown::Result,
+            carries: Regrouping::Any,
         }
         .try_into().expect("misconfigured module")
     }
@@ -105,6 +231,10 @@ pub mod presets {
         Config {
             min_val: 0,
             max_val: 9_999,
+            min_addends: 2,
+            max_addends: 2,
+            unknown: Unknown::Result,
+            carries: Regrouping::Any,
         }
         .try_into().expect("misconfigured module")
     }
@@ -113,6 +243,46 @@ pub mod presets {
         Config {
             min_val: 0,
             max_val: 99_999_999,
+            min_addends: 2,
+            max_addends: 2,
+            unknown: Unknown::Result,
+            carries: Regrouping::Any,
+        }
+        .try_into().expect("misconfigured module")
+    }
+
+    pub fn addition_4() -> Addition {
+        Config {
+            min_val: -20,
+            max_val: 21,
+            min_addends: 2,
+            max_addends: 3,
+            unknown: Unknown::Any,
+            carries: Regrouping::Any,
+        }
+        .try_into().expect("misconfigured module")
+    }
+
+    pub fn addition_5() -> Addition {
+        Config {
+            min_val: 0,
+            max_val: 9_999,
+            min_addends: 2,
+            max_addends: 2,
+            unknown: Unknown::Any,
+            carries: Regrouping::Any,
+        }
+        .try_into().expect("misconfigured module")
+    }
+
+    pub fn addition_6() -> Addition {
+        Config {
+            min_val: 0,
+            max_val: 99_999_999,
+            min_addends: 3,
+            max_addends: 4,
+            unknown: Unknown::Result,
+            carries: Regrouping::Any,
         }
         .try_into().expect("misconfigured module")
     }
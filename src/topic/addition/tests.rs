@@ -1,6 +1,8 @@
+use tinyrand::StdRand;
 use tinyrand_alloc::mock::Mock;
 use crate::topic::addition::{Addition, Config, presets};
-use crate::topic::{Module, Outcome};
+use crate::topic::regrouping::Regrouping;
+use crate::topic::{Module, Outcome, Unknown};
 
 #[test]
 fn name() {
@@ -12,10 +14,14 @@ fn name() {
 fn display_ask_answer() {
     let module = Addition::try_from(Config {
         min_val: 10,
-        max_val: 30
+        max_val: 30,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Any,
     }).unwrap();
 
-    let rand_nums = vec![12, 13];
+    let rand_nums = [12, 13];
     let mut rand = Mock::default()
         .with_next_lim_u128(|surrogate, lim| {
             assert_eq!(20, lim);
@@ -34,23 +40,278 @@ fn display_ask_answer() {
     assert_eq!(Outcome::Correct, question.answer("45"));
 }
 
+#[test]
+fn display_ask_answer_missing_operand() {
+    let module = Addition::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Operand,
+        carries: Regrouping::Any,
+    })
+    .unwrap();
+
+    let rand_nums = [12, 13, 1];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() < 2 {
+            assert_eq!(20, lim);
+        } else {
+            assert_eq!(2, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you find the missing number for me."), "{}", s);
+    assert!(s.contains("22 + ? = 45"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("45"));
+    assert_eq!(Outcome::Incorrect, question.answer("22"));
+    assert_eq!(Outcome::Correct, question.answer("23"));
+}
+
+#[test]
+fn display_ask_answer_any_unknown() {
+    let module = Addition::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Any,
+        carries: Regrouping::Any,
+    })
+    .unwrap();
+
+    for (position, expected_display, expected_answer) in [
+        (0, "? + 23 = 45", "22"),
+        (1, "22 + ? = 45", "23"),
+        (2, "22 + 23 = ?", "45"),
+    ] {
+        let rand_nums = [12, 13, position];
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() == 2 {
+                assert_eq!(3, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_several_addends() {
+    let module = Addition::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        min_addends: 3,
+        max_addends: 5,
+        unknown: Unknown::Any,
+        carries: Regrouping::Any,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 12, 13, 4, 5, 4];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(3, lim),
+            1..=4 => assert_eq!(20, lim),
+            _ => assert_eq!(5, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you add these numbers for me."), "{}", s);
+    assert!(s.contains("22 + 23 + 14 + 15 = ?"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("45"));
+    assert_eq!(Outcome::Correct, question.answer("74"));
+}
+
+#[test]
+fn display_ask_answer_signed() {
+    let module = Addition::try_from(Config {
+        min_val: -20,
+        max_val: 21,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Any,
+        carries: Regrouping::Any,
+    })
+    .unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        ([25, 17, 2], "5 + (-3) = ?", "2"),
+        ([12, 17, 2], "(-8) + (-3) = ?", "-11"),
+        ([12, 30, 1], "(-8) + ? = 2", "10"),
+        ([25, 17, 0], "? + (-3) = 2", "5"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() < 2 {
+                assert_eq!(41, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+
+    let question = module.ask(&mut StdRand::default());
+    assert_eq!(
+        Outcome::Invalid("'foo' does not appear to be a valid integer".into()),
+        question.answer("foo")
+    );
+}
+
+#[test]
+fn constrained_carries() {
+    fn carries(mut lhs: u32, mut rhs: u32) -> u32 {
+        let (mut carries, mut carry) = (0, 0);
+        while lhs > 0 || rhs > 0 {
+            carry = (lhs % 10 + rhs % 10 + carry) / 10;
+            carries += carry;
+            lhs /= 10;
+            rhs /= 10;
+        }
+        carries
+    }
+
+    let mut rand = StdRand::default();
+    for (regrouping, admits) in [
+        (Regrouping::Forbid, (|carries| carries == 0) as fn(u32) -> bool),
+        (Regrouping::Require, |carries| carries > 0),
+        (Regrouping::Exactly(2), |carries| carries == 2),
+    ] {
+        let module = Addition::try_from(Config {
+            min_val: 100,
+            max_val: 1_000,
+            min_addends: 2,
+            max_addends: 2,
+            unknown: Unknown::Result,
+            carries: regrouping,
+        })
+        .unwrap();
+
+        for _ in 0..100 {
+            let question = module.ask(&mut rand);
+            let s = format!("{}", question);
+            let equation = s.lines().last().unwrap();
+            let (lhs, rhs) = equation.trim_end_matches(" = ?").split_once(" + ").unwrap();
+            let (lhs, rhs) = (lhs.parse::<u32>().unwrap(), rhs.parse::<u32>().unwrap());
+            assert!((100..1_000).contains(&lhs), "{}", s);
+            assert!((100..1_000).contains(&rhs), "{}", s);
+            assert!(admits(carries(lhs, rhs)), "{:?}: {}", regrouping, s);
+            assert_eq!(Outcome::Correct, question.answer(&(lhs + rhs).to_string()));
+        }
+    }
+}
+
 #[test]
 fn invalid_config() {
     let module = Addition::try_from(Config {
         min_val: 10,
-        max_val: 10
+        max_val: 10,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Any,
     });
     assert_eq!("min_val must be less than max_val", module.err().unwrap());
 
     let module = Addition::try_from(Config {
         min_val: 10,
-        max_val: (u32::MAX << 1) + 1
+        max_val: 20,
+        min_addends: 1,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Any,
+    });
+    assert_eq!("min_addends must be at least 2", module.err().unwrap());
+
+    let module = Addition::try_from(Config {
+        min_val: 10,
+        max_val: 20,
+        min_addends: 3,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Any,
+    });
+    assert_eq!("min_addends cannot exceed max_addends", module.err().unwrap());
+
+    for (min_val, max_val) in [(10, i32::MAX >> 2), (-(i32::MAX >> 2), 10)] {
+        let module = Addition::try_from(Config {
+            min_val,
+            max_val,
+            min_addends: 2,
+            max_addends: 5,
+            unknown: Unknown::Result,
+            carries: Regrouping::Any,
+        });
+        assert_eq!(
+            format!(
+                "the sum of max_addends numbers between min_val and max_val cannot exceed {} in magnitude",
+                i32::MAX
+            ),
+            module.err().unwrap()
+        );
+    }
+
+    let module = Addition::try_from(Config {
+        min_val: 10,
+        max_val: 20,
+        min_addends: 2,
+        max_addends: 3,
+        unknown: Unknown::Result,
+        carries: Regrouping::Require,
+    });
+    assert_eq!(
+        "carries can only be constrained for sums of two addends",
+        module.err().unwrap()
+    );
+
+    let module = Addition::try_from(Config {
+        min_val: 0,
+        max_val: 100,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Exactly(3),
+    });
+    assert_eq!(
+        "no sums between min_val and max_val satisfy the carries constraint",
+        module.err().unwrap()
+    );
+
+    let module = Addition::try_from(Config {
+        min_val: -10,
+        max_val: 100,
+        min_addends: 2,
+        max_addends: 2,
+        unknown: Unknown::Result,
+        carries: Regrouping::Require,
     });
-    assert_eq!(format!("max_val cannot exceed {}", u32::MAX << 1), module.err().unwrap());
+    assert_eq!(
+        "carries can only be constrained for non-negative addends",
+        module.err().unwrap()
+    );
 }
 
 #[test]
 fn presets() {
     presets::addition_1();
     presets::addition_2();
+    presets::addition_3();
+    presets::addition_4();
+    presets::addition_5();
+    presets::addition_6();
 }
\ No newline at end of file
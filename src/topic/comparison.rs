@@ -0,0 +1,197 @@
+//! Questions on comparing numbers and expressions with `<`, `>` and `=`.
+
+use crate::topic::{Module, Outcome, Question};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The comparison module.
+pub struct Comparison {
+    config: Config,
+}
+
+/// Configuration for [`Comparison`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// Compare sums and differences rather than plain numbers; e.g., `23 + 9 ? 40 – 7`.
+    pub expressions: bool,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = u32::MAX >> 2;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Comparison {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Comparison {
+    fn side(&self, rand: &mut dyn RandRange<u32>) -> Side {
+        let lhs = rand.next_range(self.config.min_val..self.config.max_val);
+        if !self.config.expressions {
+            return Side::Number(lhs);
+        }
+        let rhs = rand.next_range(self.config.min_val..self.config.max_val);
+        if rand.next_range(0..2) == 0 {
+            Side::Sum(lhs, rhs)
+        } else {
+            Side::Difference(lhs.max(rhs), lhs.min(rhs))
+        }
+    }
+
+    /// Generates a side with the given value.
+    fn side_equal_to(&self, value: u32, rand: &mut dyn RandRange<u32>) -> Side {
+        if !self.config.expressions {
+            return Side::Number(value);
+        }
+        if rand.next_range(0..2) == 0 {
+            let lhs = rand.next_range(0..value + 1);
+            Side::Sum(lhs, value - lhs)
+        } else {
+            let rhs = rand.next_range(self.config.min_val..self.config.max_val);
+            Side::Difference(value + rhs, rhs)
+        }
+    }
+}
+
+impl Module for Comparison {
+    fn topic_name(&self) -> String {
+        String::from("comparison")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        // sides drawn independently are rarely equal, so a third of the questions are rigged
+        let equal = rand.next_range(0..3) == 0;
+        let lhs = self.side(rand);
+        let rhs = if equal {
+            self.side_equal_to(lhs.value(), rand)
+        } else {
+            self.side(rand)
+        };
+        Box::new(Inequality { lhs, rhs })
+    }
+}
+
+/// One side of a comparison.
+enum Side {
+    Number(u32),
+    Sum(u32, u32),
+    Difference(u32, u32),
+}
+
+impl Side {
+    fn value(&self) -> u32 {
+        match self {
+            Side::Number(number) => *number,
+            Side::Sum(lhs, rhs) => lhs + rhs,
+            Side::Difference(lhs, rhs) => lhs - rhs,
+        }
+    }
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Side::Number(number) => write!(f, "{number}"),
+            Side::Sum(lhs, rhs) => write!(f, "{lhs} + {rhs}"),
+            Side::Difference(lhs, rhs) => write!(f, "{lhs} – {rhs}"),
+        }
+    }
+}
+
+struct Inequality {
+    lhs: Side,
+    rhs: Side,
+}
+
+impl Display for Inequality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you compare these for me. Answer with <, > or =.")?;
+        write!(f, "{} ? {}", self.lhs, self.rhs)
+    }
+}
+
+impl Question for Inequality {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok(answer) => {
+                if answer == self.lhs.value().cmp(&self.rhs.value()) {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Parses one of the comparison symbols `<`, `>` or `=`.
+pub(crate) fn parse(answer: &str) -> Result<Ordering, String> {
+    match answer.trim() {
+        "<" => Ok(Ordering::Less),
+        ">" => Ok(Ordering::Greater),
+        "=" => Ok(Ordering::Equal),
+        _ => Err(format!("'{answer}' does not appear to be one of <, > or =")),
+    }
+}
+
+pub mod presets {
+    use super::{Comparison, Config};
+
+    pub fn comparison_1() -> Comparison {
+        Config {
+            min_val: 0,
+            max_val: 100,
+            expressions: false,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn comparison_2() -> Comparison {
+        Config {
+            min_val: 0,
+            max_val: 100,
+            expressions: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn comparison_3() -> Comparison {
+        Config {
+            min_val: 0,
+            max_val: 10_000,
+            expressions: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,133 @@
+use crate::topic::comparison::{parse, presets, Comparison, Config};
+use crate::topic::{Module, Outcome};
+use std::cmp::Ordering;
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::comparison_1();
+    assert_eq!("comparison", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_numbers() {
+    let module = Comparison::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        expressions: false,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 13, 4];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(3, lim);
+        } else {
+            assert_eq!(20, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you compare these for me. Answer with <, > or =."),
+        "{}",
+        s
+    );
+    assert!(s.contains("23 ? 14"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'bigger' does not appear to be one of <, > or =".into()),
+        question.answer("bigger")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("<"));
+    assert_eq!(Outcome::Incorrect, question.answer("="));
+    assert_eq!(Outcome::Correct, question.answer(">"));
+}
+
+#[test]
+fn display_ask_answer_expressions() {
+    let module = Comparison::try_from(Config {
+        min_val: 0,
+        max_val: 50,
+        expressions: true,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 23, 9, 0, 7, 40, 1];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("23 + 9 ? 40 – 7"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer(">"));
+    assert_eq!(Outcome::Incorrect, question.answer("="));
+    assert_eq!(Outcome::Correct, question.answer("<"));
+}
+
+#[test]
+fn display_ask_answer_equal() {
+    let module = Comparison::try_from(Config {
+        min_val: 0,
+        max_val: 50,
+        expressions: true,
+    })
+    .unwrap();
+
+    for (rand_nums, expected_display) in [
+        ([0, 23, 9, 0, 0, 12], "23 + 9 ? 12 + 20"),
+        ([0, 23, 9, 0, 1, 8], "23 + 9 ? 40 – 8"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() == 5 && rand_nums[4] == 0 {
+                assert_eq!(33, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer("="));
+    }
+}
+
+#[test]
+fn parse_symbols() {
+    assert_eq!(Ok(Ordering::Less), parse("<"));
+    assert_eq!(Ok(Ordering::Greater), parse(" > "));
+    assert_eq!(Ok(Ordering::Equal), parse("="));
+    assert!(parse("").is_err());
+    assert!(parse("<=").is_err());
+}
+
+#[test]
+fn invalid_config() {
+    let module = Comparison::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        expressions: false,
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Comparison::try_from(Config {
+        min_val: 10,
+        max_val: (u32::MAX >> 2) + 1,
+        expressions: false,
+    });
+    assert_eq!(
+        format!("max_val cannot exceed {}", u32::MAX >> 2),
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::comparison_1();
+    presets::comparison_2();
+    presets::comparison_3();
+}
@@ -0,0 +1,240 @@
+//! Questions on decimal arithmetic.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The decimals module.
+pub struct Decimals {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Adding two decimals; e.g., `1.25 + 3.40 = ?`.
+    Add,
+
+    /// Subtracting two decimals; e.g., `3.40 – 1.25 = ?`. The difference is never negative.
+    Subtract,
+
+    /// Multiplying a decimal by a single-digit whole number; e.g., `1.25 × 3 = ?`.
+    Multiply,
+}
+
+/// Configuration for [`Decimals`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The number of decimal places in each operand.
+    pub places: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 100_000;
+        const MAX_PLACES: u32 = 4;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.places == 0 || self.places > MAX_PLACES {
+            return Err(format!("places must be between 1 and {MAX_PLACES}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Decimals {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Decimals {
+    fn topic_name(&self) -> String {
+        String::from("decimals")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let scale = 10_u32.pow(self.config.places);
+        let operands = self.config.min_val * scale..self.config.max_val * scale;
+        let lhs = rand.next_range(operands.clone());
+        let rhs = if exercise == Exercise::Multiply {
+            rand.next_range(2..10)
+        } else {
+            rand.next_range(operands)
+        };
+        let (lhs, rhs) = if exercise == Exercise::Subtract && lhs < rhs {
+            (rhs, lhs)
+        } else {
+            (lhs, rhs)
+        };
+        Box::new(Arithmetic {
+            exercise,
+            lhs: lhs.into(),
+            rhs: rhs.into(),
+            places: self.config.places,
+        })
+    }
+}
+
+struct Arithmetic {
+    exercise: Exercise,
+
+    /// The left-hand operand, scaled by `10^places`.
+    lhs: u64,
+
+    /// The right-hand operand, scaled by `10^places`, unless multiplying, in which case the
+    /// operand is a whole number.
+    rhs: u64,
+
+    places: u32,
+}
+
+impl Arithmetic {
+    /// The expected answer, scaled by `10^places`.
+    fn expected(&self) -> u64 {
+        match self.exercise {
+            Exercise::Add => self.lhs + self.rhs,
+            Exercise::Subtract => self.lhs - self.rhs,
+            Exercise::Multiply => self.lhs * self.rhs,
+        }
+    }
+}
+
+impl Display for Arithmetic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lhs = format_scaled(self.lhs, self.places);
+        match self.exercise {
+            Exercise::Add => {
+                writeln!(f, "Can you add these decimals for me.")?;
+                write!(f, "{lhs} + {} = ?", format_scaled(self.rhs, self.places))
+            }
+            Exercise::Subtract => {
+                writeln!(f, "Can you subtract these decimals for me.")?;
+                write!(f, "{lhs} – {} = ?", format_scaled(self.rhs, self.places))
+            }
+            Exercise::Multiply => {
+                writeln!(f, "Can you multiply these numbers for me.")?;
+                write!(f, "{lhs} × {} = ?", self.rhs)
+            }
+        }
+    }
+}
+
+impl Question for Arithmetic {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_scaled(answer, self.places) {
+            Ok(answer) => {
+                if answer == self.expected() {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Formats a number that has been scaled by `10^places` as a decimal.
+pub(crate) fn format_scaled(scaled: u64, places: u32) -> String {
+    let scale = 10_u64.pow(places);
+    format!(
+        "{}.{:0width$}",
+        scaled / scale,
+        scaled % scale,
+        width = places as usize
+    )
+}
+
+/// Parses a non-negative decimal number exactly, returning it scaled by `10^places`. The number
+/// may use either a point or a comma as the decimal separator, and may omit the whole part; e.g.,
+/// `0.5`, `.5` and `0,5` are equivalent. Trailing zeros are disregarded, so `2.50` is read as
+/// `2.5`, but numbers with more than `places` significant decimal places are rejected rather than
+/// rounded.
+pub(crate) fn parse_scaled(answer: &str, places: u32) -> Result<u64, String> {
+    let err = || format!("'{answer}' does not appear to be a valid decimal number");
+    let normalised = answer.trim().replacen(',', ".", 1);
+    let (whole, fraction) = normalised
+        .split_once('.')
+        .unwrap_or((normalised.as_str(), ""));
+    let digits = |s: &str| s.chars().all(|ch| ch.is_ascii_digit());
+    if whole.len() + fraction.len() == 0 || !digits(whole) || !digits(fraction) {
+        return Err(err());
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > places as usize {
+        return Err(match places {
+            0 => format!("'{answer}' should be a whole number"),
+            1 => format!("'{answer}' has more than 1 decimal place"),
+            _ => format!("'{answer}' has more than {places} decimal places"),
+        });
+    }
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        u64::from_str(whole).map_err(|_| err())?
+    };
+    let fraction = format!("{fraction:0<width$}", width = places as usize);
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        u64::from_str(&fraction).map_err(|_| err())?
+    };
+    whole
+        .checked_mul(10_u64.pow(places))
+        .and_then(|whole| whole.checked_add(fraction))
+        .ok_or_else(err)
+}
+
+pub mod presets {
+    use super::{Config, Decimals, Exercise};
+
+    pub fn decimals_1() -> Decimals {
+        Config {
+            min_val: 0,
+            max_val: 10,
+            places: 1,
+            exercises: vec![Exercise::Add, Exercise::Subtract],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn decimals_2() -> Decimals {
+        Config {
+            min_val: 0,
+            max_val: 100,
+            places: 2,
+            exercises: vec![Exercise::Add, Exercise::Subtract, Exercise::Multiply],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
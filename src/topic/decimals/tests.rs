@@ -0,0 +1,201 @@
+use crate::topic::decimals::{format_scaled, parse_scaled, presets, Config, Decimals, Exercise};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::decimals_1();
+    assert_eq!("decimals", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_add() {
+    let module = Decimals::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        places: 1,
+        exercises: vec![Exercise::Add],
+    })
+    .unwrap();
+
+    let rand_nums = [3, 42];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        assert_eq!(100, lim);
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you add these decimals for me."), "{}", s);
+    assert!(s.contains("0.3 + 4.2 = ?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'4.5.1' does not appear to be a valid decimal number".into()),
+        question.answer("4.5.1")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("4.6"));
+    assert_eq!(Outcome::Incorrect, question.answer("45"));
+    assert_eq!(Outcome::Correct, question.answer("4.5"));
+    assert_eq!(Outcome::Correct, question.answer("4,5"));
+    assert_eq!(Outcome::Correct, question.answer("4.50"));
+    assert_eq!(
+        Outcome::Invalid("'4.504' has more than 1 decimal place".into()),
+        question.answer("4.504")
+    );
+}
+
+#[test]
+fn display_ask_answer_subtract() {
+    let module = Decimals::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        places: 2,
+        exercises: vec![Exercise::Add, Exercise::Subtract],
+    })
+    .unwrap();
+
+    let rand_nums = [1, 125, 340];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you subtract these decimals for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("3.40 – 1.25 = ?"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("2.25"));
+    assert_eq!(Outcome::Correct, question.answer("2.15"));
+}
+
+#[test]
+fn display_ask_answer_multiply() {
+    let module = Decimals::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        places: 1,
+        exercises: vec![Exercise::Multiply],
+    })
+    .unwrap();
+
+    let rand_nums = [5, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you multiply these numbers for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("0.5 × 4 = ?"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("0.2"));
+    assert_eq!(Outcome::Correct, question.answer("2"));
+    assert_eq!(Outcome::Correct, question.answer("2.0"));
+    assert_eq!(Outcome::Correct, question.answer("2.00"));
+}
+
+#[test]
+fn parse_scaled_forms() {
+    assert_eq!(Ok(5), parse_scaled("0.5", 1));
+    assert_eq!(Ok(5), parse_scaled(".5", 1));
+    assert_eq!(Ok(5), parse_scaled("0,5", 1));
+    assert_eq!(Ok(5), parse_scaled(" 0.5 ", 1));
+    assert_eq!(Ok(50), parse_scaled("0.5", 2));
+    assert_eq!(Ok(50), parse_scaled("5", 1));
+    assert_eq!(Ok(50), parse_scaled("5.", 1));
+    assert_eq!(Ok(5), parse_scaled("5", 0));
+    assert_eq!(Ok(120), parse_scaled("1.2", 2));
+    assert!(parse_scaled("", 1).is_err());
+    assert!(parse_scaled(".", 1).is_err());
+    assert!(parse_scaled("-0.5", 1).is_err());
+    assert!(parse_scaled("1e3", 1).is_err());
+    assert!(parse_scaled("0,5,1", 1).is_err());
+    assert!(parse_scaled("inf", 1).is_err());
+    assert!(parse_scaled("18446744073709551615", 1).is_err());
+}
+
+#[test]
+fn parse_scaled_trailing_zeros() {
+    assert_eq!(Ok(200), parse_scaled("2.00", 2));
+    assert_eq!(Ok(200), parse_scaled("2.000", 2));
+    assert_eq!(Ok(25), parse_scaled("2.50", 1));
+    assert_eq!(Ok(7), parse_scaled("7.0", 0));
+    assert_eq!(Ok(7), parse_scaled("7.", 0));
+}
+
+#[test]
+fn parse_scaled_rejects_extra_places() {
+    assert_eq!(
+        Err("'1.204' has more than 1 decimal place".into()),
+        parse_scaled("1.204", 1)
+    );
+    assert_eq!(
+        Err("'1.2040' has more than 2 decimal places".into()),
+        parse_scaled("1.2040", 2)
+    );
+    assert_eq!(
+        Err("'7.04' should be a whole number".into()),
+        parse_scaled("7.04", 0)
+    );
+}
+
+#[test]
+fn format() {
+    assert_eq!("0.05", format_scaled(5, 2));
+    assert_eq!("12.30", format_scaled(1230, 2));
+    assert_eq!("1.0", format_scaled(10, 1));
+}
+
+#[test]
+fn invalid_config() {
+    let module = Decimals::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        places: 1,
+        exercises: vec![Exercise::Add],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Decimals::try_from(Config {
+        min_val: 0,
+        max_val: 100_001,
+        places: 1,
+        exercises: vec![Exercise::Add],
+    });
+    assert_eq!("max_val cannot exceed 100000", module.err().unwrap());
+
+    for places in [0, 5] {
+        let module = Decimals::try_from(Config {
+            min_val: 0,
+            max_val: 10,
+            places,
+            exercises: vec![Exercise::Add],
+        });
+        assert_eq!("places must be between 1 and 4", module.err().unwrap());
+    }
+
+    let module = Decimals::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        places: 1,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::decimals_1();
+    presets::decimals_2();
+}
@@ -0,0 +1,188 @@
+//! Questions on division.
+
+use crate::topic::{Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The division module.
+pub struct Division {
+    config: Config,
+}
+
+/// Configuration for [`Division`].
+pub struct Config {
+    /// The smallest divisor that will be asked.
+    pub min_divisor: u32,
+
+    /// The largest divisor that will be asked.
+    pub max_divisor: u32,
+
+    /// The smallest quotient that will be asked.
+    pub min_quotient: u32,
+
+    /// The largest quotient that will be asked.
+    pub max_quotient: u32,
+
+    /// Allow the division to leave a remainder. When unset, only exact divisions are asked.
+    pub allow_remainder: bool,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_divisor == 0 {
+            return Err("min_divisor must be greater than zero".into());
+        }
+        if self.min_divisor >= self.max_divisor {
+            return Err("min_divisor must be less than max_divisor".into());
+        }
+        if self.min_quotient >= self.max_quotient {
+            return Err("min_quotient must be less than max_quotient".into());
+        }
+        if self.max_divisor.checked_mul(self.max_quotient).is_none() {
+            return Err(format!(
+                "the product of max_divisor and max_quotient cannot exceed {}",
+                u32::MAX
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Division {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Division {
+    fn topic_name(&self) -> String {
+        String::from("division")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let divisor = rand.next_range(self.config.min_divisor..self.config.max_divisor);
+        let quotient = rand.next_range(self.config.min_quotient..self.config.max_quotient);
+        let remainder = if self.config.allow_remainder {
+            rand.next_range(0..divisor)
+        } else {
+            0
+        };
+        Box::new(Quotient {
+            dividend: divisor * quotient + remainder,
+            divisor,
+            allow_remainder: self.config.allow_remainder,
+        })
+    }
+}
+
+struct Quotient {
+    dividend: u32,
+    divisor: u32,
+    allow_remainder: bool,
+}
+
+impl Display for Quotient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.allow_remainder {
+            writeln!(
+                f,
+                "Can you divide these two numbers for me. Don't forget the remainder, if there is one."
+            )?;
+        } else {
+            writeln!(f, "Can you divide these two numbers for me.")?;
+        }
+        write!(f, "{} ÷ {} = ?", self.dividend, self.divisor)
+    }
+}
+
+impl Question for Quotient {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok((quotient, remainder)) => {
+                let expected = (self.dividend / self.divisor, self.dividend % self.divisor);
+                if (quotient, remainder) == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Parses an answer comprising a quotient and an optional remainder. The remainder may be
+/// given as `7 r 2`, `7r2` or `7 remainder 2`. A missing remainder is taken to be zero.
+fn parse(answer: &str) -> Result<(u32, u32), String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid answer; \
+            write a natural number (e.g., '7') or a number with a remainder (e.g., '7 r 2' or '7 remainder 2')"
+        )
+    };
+    let lowercase = answer.to_lowercase();
+    let (quotient, remainder) = match lowercase
+        .split_once("remainder")
+        .or_else(|| lowercase.split_once('r'))
+    {
+        None => (lowercase.as_str(), None),
+        Some((quotient, remainder)) => (quotient, Some(remainder)),
+    };
+    let quotient = u32::from_str(quotient.trim()).map_err(|_| err())?;
+    let remainder = match remainder {
+        None => 0,
+        Some(remainder) => u32::from_str(remainder.trim()).map_err(|_| err())?,
+    };
+    Ok((quotient, remainder))
+}
+
+pub mod presets {
+    use super::{Config, Division};
+
+    pub fn division_1() -> Division {
+        Config {
+            min_divisor: 1,
+            max_divisor: 10,
+            min_quotient: 0,
+            max_quotient: 10,
+            allow_remainder: false,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn division_2() -> Division {
+        Config {
+            min_divisor: 2,
+            max_divisor: 13,
+            min_quotient: 0,
+            max_quotient: 100,
+            allow_remainder: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn division_3() -> Division {
+        Config {
+            min_divisor: 2,
+            max_divisor: 100,
+            min_quotient: 0,
+            max_quotient: 10_000,
+            allow_remainder: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+use crate::topic::division::{parse, presets, Config, Division};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::division_1();
+    assert_eq!("division", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_exact() {
+    let module = Division::try_from(Config {
+        min_divisor: 2,
+        max_divisor: 10,
+        min_quotient: 0,
+        max_quotient: 20,
+        allow_remainder: false,
+    })
+    .unwrap();
+
+    let rand_nums = [5, 12];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(8, lim);
+        } else {
+            assert_eq!(20, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you divide these two numbers for me."),
+        "{}",
+        s
+    );
+    assert!(!s.contains("remainder"), "{}", s);
+    assert!(s.contains("84 ÷ 7"), "{}", s);
+    assert_eq!(2, rand.state().next_lim_u128_invocations());
+
+    assert!(matches!(question.answer("foo"), Outcome::Invalid(_)));
+    assert_eq!(Outcome::Incorrect, question.answer("11"));
+    assert_eq!(Outcome::Incorrect, question.answer("12 r 1"));
+    assert_eq!(Outcome::Correct, question.answer("12"));
+    assert_eq!(Outcome::Correct, question.answer("12 r 0"));
+}
+
+#[test]
+fn display_ask_answer_remainder() {
+    let module = Division::try_from(Config {
+        min_divisor: 2,
+        max_divisor: 10,
+        min_quotient: 0,
+        max_quotient: 20,
+        allow_remainder: true,
+    })
+    .unwrap();
+
+    let rand_nums = [5, 12, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(8, lim),
+            1 => assert_eq!(20, lim),
+            _ => assert_eq!(7, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Don't forget the remainder"), "{}", s);
+    assert!(s.contains("87 ÷ 7"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'12 r x' does not appear to be a valid answer; write a natural number (e.g., '7') or a number with a remainder (e.g., '7 r 2' or '7 remainder 2')".into()),
+        question.answer("12 r x")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("12"));
+    assert_eq!(Outcome::Incorrect, question.answer("12 r 2"));
+    assert_eq!(Outcome::Correct, question.answer("12 r 3"));
+    assert_eq!(Outcome::Correct, question.answer("12r3"));
+    assert_eq!(Outcome::Correct, question.answer("12 R 3"));
+    assert_eq!(Outcome::Correct, question.answer("12 remainder 3"));
+}
+
+#[test]
+fn parse_formats() {
+    assert_eq!(Ok((7, 0)), parse("7"));
+    assert_eq!(Ok((7, 2)), parse("7 r 2"));
+    assert_eq!(Ok((7, 2)), parse("7r2"));
+    assert_eq!(Ok((7, 2)), parse("7 Remainder 2"));
+    assert!(parse("").is_err());
+    assert!(parse("r 2").is_err());
+    assert!(parse("7 r").is_err());
+    assert!(parse("7 x 2").is_err());
+    assert!(parse("-7").is_err());
+}
+
+#[test]
+fn invalid_config() {
+    let module = Division::try_from(Config {
+        min_divisor: 0,
+        max_divisor: 10,
+        min_quotient: 0,
+        max_quotient: 10,
+        allow_remainder: false,
+    });
+    assert_eq!(
+        "min_divisor must be greater than zero",
+        module.err().unwrap()
+    );
+
+    let module = Division::try_from(Config {
+        min_divisor: 10,
+        max_divisor: 10,
+        min_quotient: 0,
+        max_quotient: 10,
+        allow_remainder: false,
+    });
+    assert_eq!(
+        "min_divisor must be less than max_divisor",
+        module.err().unwrap()
+    );
+
+    let module = Division::try_from(Config {
+        min_divisor: 1,
+        max_divisor: 10,
+        min_quotient: 10,
+        max_quotient: 10,
+        allow_remainder: false,
+    });
+    assert_eq!(
+        "min_quotient must be less than max_quotient",
+        module.err().unwrap()
+    );
+
+    let module = Division::try_from(Config {
+        min_divisor: 1,
+        max_divisor: 1 << 16,
+        min_quotient: 0,
+        max_quotient: 1 << 16,
+        allow_remainder: true,
+    });
+    assert_eq!(
+        format!(
+            "the product of max_divisor and max_quotient cannot exceed {}",
+            u32::MAX
+        ),
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::division_1();
+    presets::division_2();
+    presets::division_3();
+}
@@ -0,0 +1,239 @@
+//! Questions on solving linear equations.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The equations module.
+pub struct Equations {
+    config: Config,
+}
+
+/// Configuration for [`Equations`].
+pub struct Config {
+    /// The smallest coefficient of `x` that will be asked.
+    pub min_coefficient: u32,
+
+    /// The upper bound (exclusive) on the coefficient of `x`.
+    pub max_coefficient: u32,
+
+    /// The upper bound (exclusive) on the constant added to or subtracted from `x`.
+    pub max_constant: u32,
+
+    /// The upper bound (exclusive) on the absolute value of the solution.
+    pub max_solution: u32,
+
+    /// Allow the solution, and hence the right-hand side, to be negative. Otherwise, neither
+    /// is ever negative.
+    pub allow_negative: bool,
+
+    /// Ask two-step equations, such as `3x + 4 = 19`, in addition to one-step equations,
+    /// such as `x + 4 = 9` and `3x = 12`.
+    pub two_step: bool,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 10_000;
+        if self.min_coefficient < 2 {
+            return Err("min_coefficient must be at least 2".into());
+        }
+        if self.min_coefficient >= self.max_coefficient {
+            return Err("min_coefficient must be less than max_coefficient".into());
+        }
+        if self.max_constant < 2 {
+            return Err("max_constant must be at least 2".into());
+        }
+        if self.max_solution < 2 {
+            return Err("max_solution must be at least 2".into());
+        }
+        for (name, value) in [
+            ("max_coefficient", self.max_coefficient),
+            ("max_constant", self.max_constant),
+            ("max_solution", self.max_solution),
+        ] {
+            if value > MAX_MAX_VAL {
+                return Err(format!("{name} cannot exceed {MAX_MAX_VAL}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Equations {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+/// The forms of equations that may be asked, where `a` is the coefficient and `b` is the
+/// constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    /// `x + b = c`
+    Add,
+
+    /// `x – b = c`
+    Subtract,
+
+    /// `ax = c`
+    Multiply,
+
+    /// `ax + b = c`
+    MultiplyAdd,
+
+    /// `ax – b = c`
+    MultiplySubtract,
+}
+
+impl Module for Equations {
+    fn topic_name(&self) -> String {
+        String::from("equations")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let forms: &[Form] = if self.config.two_step {
+            &[
+                Form::Add,
+                Form::Subtract,
+                Form::Multiply,
+                Form::MultiplyAdd,
+                Form::MultiplySubtract,
+            ]
+        } else {
+            &[Form::Add, Form::Subtract, Form::Multiply]
+        };
+        let form = *choose(forms, rand);
+        let subtract = matches!(form, Form::Subtract | Form::MultiplySubtract);
+        let max_solution = i64::from(self.config.max_solution);
+        let solution = if self.config.allow_negative {
+            i64::from(rand.next_range(0..self.config.max_solution * 2 - 1)) - (max_solution - 1)
+        } else if subtract {
+            // a zero solution would leave nothing to subtract from
+            i64::from(rand.next_range(1..self.config.max_solution))
+        } else {
+            i64::from(rand.next_range(0..self.config.max_solution))
+        };
+        let coefficient = match form {
+            Form::Add | Form::Subtract => 1,
+            Form::Multiply | Form::MultiplyAdd | Form::MultiplySubtract => {
+                rand.next_range(self.config.min_coefficient..self.config.max_coefficient)
+            }
+        };
+        let constant = match form {
+            Form::Multiply => 0,
+            Form::Add | Form::MultiplyAdd => {
+                i64::from(rand.next_range(1..self.config.max_constant))
+            }
+            Form::Subtract | Form::MultiplySubtract => {
+                // without negatives, the constant cannot exceed the product it is taken from
+                let max_constant = if self.config.allow_negative {
+                    self.config.max_constant
+                } else {
+                    let product = u32::try_from(i64::from(coefficient) * solution).unwrap();
+                    self.config.max_constant.min(product + 1)
+                };
+                -i64::from(rand.next_range(1..max_constant))
+            }
+        };
+        Box::new(Equation {
+            coefficient: coefficient.into(),
+            constant,
+            solution,
+        })
+    }
+}
+
+/// An equation of the form `ax + b = c`.
+struct Equation {
+    coefficient: i64,
+    constant: i64,
+    solution: i64,
+}
+
+impl Display for Equation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you solve this equation for x.")?;
+        if self.coefficient == 1 {
+            write!(f, "x")?;
+        } else {
+            write!(f, "{}x", self.coefficient)?;
+        }
+        match self.constant {
+            0 => {}
+            constant if constant < 0 => write!(f, " – {}", -constant)?,
+            constant => write!(f, " + {constant}")?,
+        }
+        write!(f, " = {}", self.coefficient * self.solution + self.constant)
+    }
+}
+
+impl Question for Equation {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok(answer) => {
+                if answer == self.solution {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Parses the solution, which may be given as a plain integer (`5`) or as an assignment to `x`
+/// (`x = 5`).
+fn parse(answer: &str) -> Result<i64, String> {
+    let trimmed = answer.trim();
+    let value = match trimmed.split_once('=') {
+        None => trimmed,
+        Some((variable, value)) if variable.trim().eq_ignore_ascii_case("x") => value.trim(),
+        Some(_) => "",
+    };
+    i64::from_str(value).map_err(|_| {
+        format!("'{answer}' does not appear to be a valid solution; write an integer (e.g., '5' or 'x = 5')")
+    })
+}
+
+pub mod presets {
+    use super::{Config, Equations};
+
+    pub fn equations_1() -> Equations {
+        Config {
+            min_coefficient: 2,
+            max_coefficient: 10,
+            max_constant: 20,
+            max_solution: 13,
+            allow_negative: false,
+            two_step: false,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn equations_2() -> Equations {
+        Config {
+            min_coefficient: 2,
+            max_coefficient: 13,
+            max_constant: 50,
+            max_solution: 21,
+            allow_negative: true,
+            two_step: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
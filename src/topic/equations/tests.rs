@@ -0,0 +1,214 @@
+use crate::topic::equations::{parse, presets, Config, Equations};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+fn config(allow_negative: bool, two_step: bool) -> Config {
+    Config {
+        min_coefficient: 2,
+        max_coefficient: 10,
+        max_constant: 20,
+        max_solution: 13,
+        allow_negative,
+        two_step,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::equations_1();
+    assert_eq!("equations", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_two_step() {
+    let module = Equations::try_from(config(false, true)).unwrap();
+
+    let rand_nums = [3, 5, 1, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(5, lim),
+            1 => assert_eq!(13, lim),
+            2 => assert_eq!(8, lim),
+            _ => assert_eq!(19, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you solve this equation for x."), "{}", s);
+    assert!(s.contains("3x + 4 = 19"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'y = 5' does not appear to be a valid solution; write an integer (e.g., '5' or 'x = 5')"
+                .into()
+        ),
+        question.answer("y = 5")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("4"));
+    assert_eq!(Outcome::Correct, question.answer("5"));
+    assert_eq!(Outcome::Correct, question.answer("x = 5"));
+    assert_eq!(Outcome::Correct, question.answer("X=5"));
+}
+
+#[test]
+fn display_ask_answer_one_step() {
+    let module = Equations::try_from(config(false, false)).unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        (vec![0, 5, 3], "x + 4 = 9", "5"),
+        (vec![1, 6, 3], "x – 4 = 3", "7"),
+        (vec![1, 0, 0], "x – 1 = 0", "1"),
+        (vec![2, 4, 1], "3x = 12", "4"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() == 0 {
+                assert_eq!(3, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+        assert_eq!(
+            rand_nums.len() as u64,
+            rand.state().next_lim_u128_invocations()
+        );
+    }
+}
+
+#[test]
+fn display_ask_answer_negative() {
+    let module = Equations::try_from(config(true, true)).unwrap();
+
+    let rand_nums = [4, 7, 0, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 1 {
+            assert_eq!(25, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("2x – 3 = -13"), "{}", s);
+    assert_eq!(Outcome::Incorrect, question.answer("5"));
+    assert_eq!(Outcome::Correct, question.answer("-5"));
+    assert_eq!(Outcome::Correct, question.answer("x = -5"));
+}
+
+#[test]
+fn solutions_below_max_solution() {
+    for allow_negative in [false, true] {
+        let module = Equations::try_from(config(allow_negative, true)).unwrap();
+        let mut rand = StdRand::default();
+        for _ in 0..100 {
+            let question = module.ask(&mut rand);
+            let solution = (-20..=20)
+                .find(|solution: &i32| question.answer(&solution.to_string()) == Outcome::Correct)
+                .unwrap();
+            assert!(solution.abs() < 13, "{question}");
+            assert!(allow_negative || solution >= 0, "{question}");
+            let negative_rhs = question.to_string().contains("= -");
+            assert!(allow_negative || !negative_rhs, "{question}");
+        }
+    }
+}
+
+#[test]
+fn parse_forms() {
+    assert_eq!(Ok(5), parse("5"));
+    assert_eq!(Ok(-5), parse(" -5 "));
+    assert_eq!(Ok(5), parse("x = 5"));
+    assert_eq!(Ok(5), parse("x=5"));
+    assert!(parse("").is_err());
+    assert!(parse("x =").is_err());
+    assert!(parse("= 5").is_err());
+    assert!(parse("x = y").is_err());
+}
+
+#[test]
+fn invalid_config() {
+    let module = Equations::try_from(Config {
+        min_coefficient: 1,
+        max_coefficient: 10,
+        max_constant: 20,
+        max_solution: 13,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("min_coefficient must be at least 2", module.err().unwrap());
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 2,
+        max_constant: 20,
+        max_solution: 13,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!(
+        "min_coefficient must be less than max_coefficient",
+        module.err().unwrap()
+    );
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 10,
+        max_constant: 1,
+        max_solution: 13,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("max_constant must be at least 2", module.err().unwrap());
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 10,
+        max_constant: 20,
+        max_solution: 1,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("max_solution must be at least 2", module.err().unwrap());
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 10_001,
+        max_constant: 20,
+        max_solution: 13,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("max_coefficient cannot exceed 10000", module.err().unwrap());
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 10,
+        max_constant: 10_001,
+        max_solution: 13,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("max_constant cannot exceed 10000", module.err().unwrap());
+
+    let module = Equations::try_from(Config {
+        min_coefficient: 2,
+        max_coefficient: 10,
+        max_constant: 20,
+        max_solution: 10_001,
+        allow_negative: false,
+        two_step: false,
+    });
+    assert_eq!("max_solution cannot exceed 10000", module.err().unwrap());
+}
+
+#[test]
+fn presets() {
+    presets::equations_1();
+    presets::equations_2();
+}
@@ -0,0 +1,296 @@
+//! Questions on the order of operations, using expressions with brackets.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The expressions module.
+pub struct Expressions {
+    config: Config,
+}
+
+/// An arithmetic operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn precedence(self) -> u8 {
+        match self {
+            Operator::Add | Operator::Subtract => 0,
+            Operator::Multiply | Operator::Divide => 1,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "–",
+            Operator::Multiply => "×",
+            Operator::Divide => "÷",
+        }
+    }
+}
+
+/// Configuration for [`Expressions`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The number of levels of operators in an expression; e.g., `(4 + 3) × 2 – 5` has a depth
+    /// of 3.
+    pub depth: u32,
+
+    /// The operators to choose from.
+    pub operators: Vec<Operator>,
+}
+
+/// The largest divisor that will be asked.
+const MAX_DIVISOR: u32 = 12;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_DEPTH: u32 = 4;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.depth == 0 || self.depth > MAX_DEPTH {
+            return Err(format!("depth must be between 1 and {MAX_DEPTH}"));
+        }
+        if self.operators.is_empty() {
+            return Err("at least one operator must be specified".into());
+        }
+        // in the worst case, every operator is a multiplication
+        let max_val = u64::from(self.max_val.max(MAX_DIVISOR + 1));
+        if max_val.checked_pow(1 << self.depth).is_none() {
+            return Err("max_val is too large for the given depth".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Expressions {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Expressions {
+    /// Generates an expression with the given number of levels of operators. Differences are
+    /// never negative and quotients are always whole.
+    fn generate(&self, depth: u32, rand: &mut dyn RandRange<u32>) -> Expr {
+        if depth == 0 {
+            return Expr::Number(
+                rand.next_range(self.config.min_val..self.config.max_val)
+                    .into(),
+            );
+        }
+
+        let operator = *choose(&self.config.operators, rand);
+        let shallow_depth = rand.next_range(0..depth);
+        let (lhs_depth, rhs_depth) = if rand.next_range(0..2) == 0 {
+            (depth - 1, shallow_depth)
+        } else {
+            (shallow_depth, depth - 1)
+        };
+        let lhs = self.generate(lhs_depth, rand);
+        match operator {
+            Operator::Divide => {
+                let dividend = lhs.evaluate();
+                let divisors = (2..=u64::from(MAX_DIVISOR))
+                    .filter(|divisor| dividend.is_multiple_of(*divisor))
+                    .collect::<Vec<_>>();
+                if divisors.is_empty() {
+                    // the dividend cannot be divided evenly, so it is replaced with a multiple
+                    let divisor = rand.next_range(2..MAX_DIVISOR + 1);
+                    let quotient = rand.next_range(self.config.min_val..self.config.max_val);
+                    Expr::binary(
+                        Expr::Number(u64::from(divisor) * u64::from(quotient)),
+                        operator,
+                        Expr::Number(divisor.into()),
+                    )
+                } else {
+                    let divisor = *choose(&divisors, rand);
+                    Expr::binary(lhs, operator, Expr::Number(divisor))
+                }
+            }
+            Operator::Subtract => {
+                let rhs = self.generate(rhs_depth, rand);
+                if lhs.evaluate() < rhs.evaluate() {
+                    Expr::binary(rhs, operator, lhs)
+                } else {
+                    Expr::binary(lhs, operator, rhs)
+                }
+            }
+            Operator::Add | Operator::Multiply => {
+                let rhs = self.generate(rhs_depth, rand);
+                Expr::binary(lhs, operator, rhs)
+            }
+        }
+    }
+}
+
+impl Module for Expressions {
+    fn topic_name(&self) -> String {
+        String::from("expressions")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        Box::new(Evaluation {
+            expr: self.generate(self.config.depth, rand),
+        })
+    }
+}
+
+/// An expression tree over the natural numbers.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Expr {
+    Number(u64),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+impl Expr {
+    pub(crate) fn binary(lhs: Expr, operator: Operator, rhs: Expr) -> Self {
+        Expr::Binary(Box::new(lhs), operator, Box::new(rhs))
+    }
+
+    /// Evaluates the expression.
+    ///
+    /// # Panics
+    /// If a difference is negative or a divisor is zero.
+    pub(crate) fn evaluate(&self) -> u64 {
+        match self {
+            Expr::Number(number) => *number,
+            Expr::Binary(lhs, operator, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate(), rhs.evaluate());
+                match operator {
+                    Operator::Add => lhs + rhs,
+                    Operator::Subtract => lhs - rhs,
+                    Operator::Multiply => lhs * rhs,
+                    Operator::Divide => lhs / rhs,
+                }
+            }
+        }
+    }
+
+    fn operator(&self) -> Option<Operator> {
+        match self {
+            Expr::Number(_) => None,
+            Expr::Binary(_, operator, _) => Some(*operator),
+        }
+    }
+}
+
+impl Display for Expr {
+    /// Renders the expression using as few brackets as possible.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Number(number) => write!(f, "{number}"),
+            Expr::Binary(lhs, operator, rhs) => {
+                let lhs_brackets = lhs
+                    .operator()
+                    .is_some_and(|lhs| lhs.precedence() < operator.precedence());
+                let rhs_brackets = rhs.operator().is_some_and(|rhs| {
+                    rhs.precedence() < operator.precedence()
+                        || rhs.precedence() == operator.precedence()
+                            && !(rhs == *operator
+                                && matches!(operator, Operator::Add | Operator::Multiply))
+                });
+                if lhs_brackets {
+                    write!(f, "({lhs})")?;
+                } else {
+                    write!(f, "{lhs}")?;
+                }
+                write!(f, " {} ", operator.symbol())?;
+                if rhs_brackets {
+                    write!(f, "({rhs})")
+                } else {
+                    write!(f, "{rhs}")
+                }
+            }
+        }
+    }
+}
+
+struct Evaluation {
+    expr: Expr,
+}
+
+impl Display for Evaluation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you work this out for me. Remember the order of operations."
+        )?;
+        write!(f, "{} = ?", self.expr)
+    }
+}
+
+impl Question for Evaluation {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok(answer) => {
+                if answer == self.expr.evaluate() {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+fn parse(answer: &str) -> Result<u64, String> {
+    u64::from_str(answer)
+        .map_err(|_| format!("'{answer}' does not appear to be a valid natural number"))
+}
+
+pub mod presets {
+    use super::{Config, Expressions, Operator};
+
+    pub fn expressions_1() -> Expressions {
+        Config {
+            min_val: 0,
+            max_val: 10,
+            depth: 2,
+            operators: vec![Operator::Add, Operator::Subtract],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn expressions_2() -> Expressions {
+        Config {
+            min_val: 0,
+            max_val: 13,
+            depth: 3,
+            operators: vec![
+                Operator::Add,
+                Operator::Subtract,
+                Operator::Multiply,
+                Operator::Divide,
+            ],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,185 @@
+use crate::topic::expressions::{presets, Config, Evaluation, Expr, Expressions, Operator};
+use crate::topic::{Module, Outcome, Question};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::expressions_1();
+    assert_eq!("expressions", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer() {
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        depth: 2,
+        operators: vec![Operator::Add, Operator::Multiply],
+    })
+    .unwrap();
+
+    // × with the deeper operand on the left, which is the sum of 4 and 3; then 2 on the right
+    let rand_nums = [1, 0, 0, 0, 0, 0, 4, 3, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you work this out for me. Remember the order of operations."),
+        "{}",
+        s
+    );
+    assert!(s.contains("(4 + 3) × 2 = ?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'foo' does not appear to be a valid natural number".into()),
+        question.answer("foo")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("10"));
+    assert_eq!(Outcome::Correct, question.answer("14"));
+}
+
+#[test]
+fn render_and_evaluate() {
+    fn num(number: u64) -> Expr {
+        Expr::Number(number)
+    }
+    use Operator::{Add, Divide, Multiply, Subtract};
+
+    for (expr, expected_display, expected_value) in [
+        (
+            Expr::binary(
+                Expr::binary(Expr::binary(num(4), Add, num(3)), Multiply, num(2)),
+                Subtract,
+                num(5),
+            ),
+            "(4 + 3) × 2 – 5",
+            9,
+        ),
+        (
+            Expr::binary(num(4), Add, Expr::binary(num(3), Multiply, num(2))),
+            "4 + 3 × 2",
+            10,
+        ),
+        (
+            Expr::binary(num(10), Subtract, Expr::binary(num(4), Subtract, num(3))),
+            "10 – (4 – 3)",
+            9,
+        ),
+        (
+            Expr::binary(Expr::binary(num(10), Subtract, num(4)), Subtract, num(3)),
+            "10 – 4 – 3",
+            3,
+        ),
+        (
+            Expr::binary(num(10), Add, Expr::binary(num(4), Add, num(3))),
+            "10 + 4 + 3",
+            17,
+        ),
+        (
+            Expr::binary(num(10), Add, Expr::binary(num(4), Subtract, num(3))),
+            "10 + (4 – 3)",
+            11,
+        ),
+        (
+            Expr::binary(num(24), Divide, Expr::binary(num(6), Divide, num(2))),
+            "24 ÷ (6 ÷ 2)",
+            8,
+        ),
+        (
+            Expr::binary(
+                Expr::binary(num(24), Divide, num(6)),
+                Multiply,
+                Expr::binary(num(2), Multiply, num(3)),
+            ),
+            "24 ÷ 6 × 2 × 3",
+            24,
+        ),
+    ] {
+        assert_eq!(expected_display, format!("{expr}"));
+        assert_eq!(expected_value, expr.evaluate(), "{expr}");
+    }
+}
+
+#[test]
+fn generated_expressions_are_sound() {
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        depth: 3,
+        operators: vec![
+            Operator::Add,
+            Operator::Subtract,
+            Operator::Multiply,
+            Operator::Divide,
+        ],
+    })
+    .unwrap();
+
+    let mut rand = StdRand::default();
+    for _ in 0..1_000 {
+        // evaluation panics on negative differences and zero divisors
+        let expr = module.generate(3, &mut rand);
+        let expected = expr.evaluate().to_string();
+        let question = Evaluation { expr };
+        assert_eq!(Outcome::Correct, question.answer(&expected), "{question}");
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Expressions::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        depth: 2,
+        operators: vec![Operator::Add],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        depth: 0,
+        operators: vec![Operator::Add],
+    });
+    assert_eq!("depth must be between 1 and 4", module.err().unwrap());
+
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        depth: 5,
+        operators: vec![Operator::Add],
+    });
+    assert_eq!("depth must be between 1 and 4", module.err().unwrap());
+
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        depth: 2,
+        operators: vec![],
+    });
+    assert_eq!(
+        "at least one operator must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Expressions::try_from(Config {
+        min_val: 0,
+        max_val: 1_000,
+        depth: 4,
+        operators: vec![Operator::Add],
+    });
+    assert_eq!(
+        "max_val is too large for the given depth",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::expressions_1();
+    presets::expressions_2();
+}
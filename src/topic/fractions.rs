@@ -0,0 +1,359 @@
+//! Questions on fractions.
+
+use crate::topic::{choose, comparison, gcd, Module, Outcome, Question};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The fractions module.
+pub struct Fractions {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Adding two fractions; e.g., `1/2 + 1/3 = ?`.
+    Add,
+
+    /// Subtracting two fractions; e.g., `3/4 – 1/3 = ?`. The difference is never negative.
+    Subtract,
+
+    /// Comparing two fractions with `<`, `>` or `=`.
+    Compare,
+
+    /// Reducing a fraction to its lowest terms; e.g., `6/8 = ?`.
+    Simplify,
+}
+
+/// Configuration for [`Fractions`].
+pub struct Config {
+    /// The smallest denominator that will be asked.
+    pub min_denominator: u32,
+
+    /// The largest denominator that will be asked.
+    pub max_denominator: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_DENOMINATOR: u32 = 1_000;
+        if self.min_denominator < 2 {
+            return Err("min_denominator must be at least 2".into());
+        }
+        if self.min_denominator >= self.max_denominator {
+            return Err("min_denominator must be less than max_denominator".into());
+        }
+        if self.max_denominator > MAX_MAX_DENOMINATOR {
+            return Err(format!(
+                "max_denominator cannot exceed {MAX_MAX_DENOMINATOR}"
+            ));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Fractions {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Fractions {
+    /// Generates a proper fraction with a denominator in the configured range.
+    fn proper_fraction(&self, rand: &mut dyn RandRange<u32>) -> Fraction {
+        let denominator = rand.next_range(self.config.min_denominator..self.config.max_denominator);
+        let numerator = rand.next_range(1..denominator);
+        Fraction::new(numerator.into(), denominator.into())
+    }
+}
+
+impl Module for Fractions {
+    fn topic_name(&self) -> String {
+        String::from("fractions")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        const MAX_FACTOR: u32 = 6;
+        let exercise = *choose(&self.config.exercises, rand);
+        match exercise {
+            Exercise::Add | Exercise::Subtract => {
+                let lhs = self.proper_fraction(rand);
+                let rhs = self.proper_fraction(rand);
+                let (lhs, rhs) = if exercise == Exercise::Subtract && lhs < rhs {
+                    (rhs, lhs)
+                } else {
+                    (lhs, rhs)
+                };
+                Box::new(Arithmetic {
+                    lhs,
+                    rhs,
+                    subtract: exercise == Exercise::Subtract,
+                })
+            }
+            Exercise::Compare => {
+                let lhs = self.proper_fraction(rand);
+                let rhs = self.proper_fraction(rand);
+                Box::new(Comparison { lhs, rhs })
+            }
+            Exercise::Simplify => {
+                let fraction = self.proper_fraction(rand);
+                let factor = u64::from(rand.next_range(2..MAX_FACTOR));
+                Box::new(Simplification {
+                    fraction: Fraction::new(
+                        fraction.numerator * factor,
+                        fraction.denominator * factor,
+                    ),
+                })
+            }
+        }
+    }
+}
+
+/// A non-negative fraction, not necessarily in its lowest terms.
+#[derive(Debug, Clone, Copy)]
+struct Fraction {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl Fraction {
+    fn new(numerator: u64, denominator: u64) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    fn reduce(self) -> Self {
+        let gcd = gcd(self.numerator, self.denominator);
+        Self::new(self.numerator / gcd, self.denominator / gcd)
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = u128::from(self.numerator) * u128::from(other.denominator);
+        let rhs = u128::from(other.numerator) * u128::from(self.denominator);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl Display for Fraction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+struct Arithmetic {
+    lhs: Fraction,
+    rhs: Fraction,
+    subtract: bool,
+}
+
+impl Display for Arithmetic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.subtract {
+            writeln!(
+                f,
+                "Can you subtract these fractions for me. Simplify your answer."
+            )?;
+            write!(f, "{} – {} = ?", self.lhs, self.rhs)
+        } else {
+            writeln!(
+                f,
+                "Can you add these fractions for me. Simplify your answer."
+            )?;
+            write!(f, "{} + {} = ?", self.lhs, self.rhs)
+        }
+    }
+}
+
+impl Question for Arithmetic {
+    fn answer(&self, answer: &str) -> Outcome {
+        let denominator = self.lhs.denominator * self.rhs.denominator;
+        let lhs = self.lhs.numerator * self.rhs.denominator;
+        let rhs = self.rhs.numerator * self.lhs.denominator;
+        let expected = if self.subtract {
+            Fraction::new(lhs - rhs, denominator)
+        } else {
+            Fraction::new(lhs + rhs, denominator)
+        };
+        assess(answer, expected)
+    }
+}
+
+struct Comparison {
+    lhs: Fraction,
+    rhs: Fraction,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you compare these fractions for me. Answer with <, > or =."
+        )?;
+        write!(f, "{} ? {}", self.lhs, self.rhs)
+    }
+}
+
+impl Question for Comparison {
+    fn answer(&self, answer: &str) -> Outcome {
+        let ordering = match comparison::parse(answer) {
+            Ok(ordering) => ordering,
+            Err(err) => return Outcome::Invalid(err),
+        };
+        if ordering == self.lhs.cmp(&self.rhs) {
+            Outcome::Correct
+        } else {
+            Outcome::Incorrect
+        }
+    }
+}
+
+struct Simplification {
+    fraction: Fraction,
+}
+
+impl Display for Simplification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you simplify this fraction for me.")?;
+        write!(f, "{} = ?", self.fraction)
+    }
+}
+
+impl Question for Simplification {
+    fn answer(&self, answer: &str) -> Outcome {
+        assess(answer, self.fraction)
+    }
+}
+
+/// Assesses an answer against the expected value, which must be given in its simplest form to
+/// be deemed correct.
+fn assess(answer: &str, expected: Fraction) -> Outcome {
+    match parse(answer) {
+        Ok((answer, simplified)) => {
+            if answer != expected {
+                Outcome::Incorrect
+            } else if simplified {
+                Outcome::Correct
+            } else {
+                Outcome::Unsimplified
+            }
+        }
+        Err(err) => Outcome::Invalid(err),
+    }
+}
+
+/// Parses a whole number (`2`), a fraction (`3/4`) or a mixed number (`1 1/2`), returning its
+/// value alongside a flag indicating whether it was written in its simplest form. A fraction is
+/// in its simplest form if it cannot be reduced and cannot be written as a whole number. The
+/// fractional part of a mixed number must also be proper.
+fn parse(answer: &str) -> Result<(Fraction, bool), String> {
+    const MAX_PART: u64 = 1_000_000;
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid fraction; \
+            write a whole number (e.g., '2'), a fraction (e.g., '3/4') or a mixed number (e.g., '1 1/2')"
+        )
+    };
+    let parse_u64 = |s: &str| {
+        let part = u64::from_str(s.trim()).map_err(|_| err())?;
+        if part > MAX_PART {
+            return Err(format!(
+                "'{answer}' is too large; the numbers in a fraction cannot exceed {MAX_PART}"
+            ));
+        }
+        Ok(part)
+    };
+    let (whole, fraction) = match answer.trim().split_once('/') {
+        None => return Ok((Fraction::new(parse_u64(answer)?, 1), true)),
+        Some((numerator, denominator)) => {
+            let numerator = numerator.trim();
+            let (whole, numerator) = match numerator.rsplit_once(' ') {
+                None => (None, numerator),
+                Some((whole, numerator)) => (Some(parse_u64(whole)?), numerator),
+            };
+            let numerator = parse_u64(numerator)?;
+            let denominator = parse_u64(denominator)?;
+            if denominator == 0 {
+                return Err("the denominator cannot be zero".into());
+            }
+            (whole, Fraction::new(numerator, denominator))
+        }
+    };
+    let reduced = fraction.reduce();
+    let simplified = fraction.numerator != 0 && reduced.denominator == fraction.denominator;
+    match whole {
+        None => Ok((fraction, simplified && fraction.denominator != 1)),
+        Some(whole) => {
+            let proper = fraction.numerator < fraction.denominator;
+            let value = Fraction::new(
+                whole * fraction.denominator + fraction.numerator,
+                fraction.denominator,
+            );
+            Ok((value, simplified && proper && whole != 0))
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Exercise, Fractions};
+
+    pub fn fractions_1() -> Fractions {
+        Config {
+            min_denominator: 2,
+            max_denominator: 11,
+            exercises: vec![Exercise::Compare, Exercise::Simplify],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn fractions_2() -> Fractions {
+        Config {
+            min_denominator: 2,
+            max_denominator: 13,
+            exercises: vec![
+                Exercise::Add,
+                Exercise::Subtract,
+                Exercise::Compare,
+                Exercise::Simplify,
+            ],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
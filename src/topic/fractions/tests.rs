@@ -0,0 +1,228 @@
+use crate::topic::fractions::{parse, presets, Config, Exercise, Fraction, Fractions};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+fn module(exercises: Vec<Exercise>) -> Fractions {
+    Fractions::try_from(Config {
+        min_denominator: 2,
+        max_denominator: 10,
+        exercises,
+    })
+    .unwrap()
+}
+
+#[test]
+fn name() {
+    let module = presets::fractions_1();
+    assert_eq!("fractions", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_add() {
+    let module = module(vec![Exercise::Add]);
+
+    let rand_nums = [2, 2, 4, 4];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you add these fractions for me. Simplify your answer."),
+        "{}",
+        s
+    );
+    assert!(s.contains("3/4 + 5/6 = ?"), "{}", s);
+
+    assert!(matches!(question.answer("foo"), Outcome::Invalid(_)));
+    assert_eq!(
+        Outcome::Invalid("the denominator cannot be zero".into()),
+        question.answer("19/0")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("8/10"));
+    assert_eq!(Outcome::Correct, question.answer("19/12"));
+    assert_eq!(Outcome::Correct, question.answer("1 7/12"));
+    assert_eq!(Outcome::Unsimplified, question.answer("38/24"));
+    assert_eq!(Outcome::Unsimplified, question.answer("1 14/24"));
+}
+
+#[test]
+fn display_ask_answer_subtract() {
+    let module = module(vec![Exercise::Subtract]);
+
+    // 1/4, 3/4 are swapped so that the difference is positive
+    let rand_nums = [2, 0, 2, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you subtract these fractions for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("3/4 – 1/4 = ?"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("1/4"));
+    assert_eq!(Outcome::Unsimplified, question.answer("2/4"));
+    assert_eq!(Outcome::Correct, question.answer("1/2"));
+}
+
+#[test]
+fn display_ask_answer_compare() {
+    let module = module(vec![Exercise::Compare]);
+
+    let rand_nums = [2, 1, 1, 0];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Answer with <, > or ="), "{}", s);
+    assert!(s.contains("2/4 ? 1/3"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'bigger' does not appear to be one of <, > or =".into()),
+        question.answer("bigger")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("<"));
+    assert_eq!(Outcome::Incorrect, question.answer("="));
+    assert_eq!(Outcome::Correct, question.answer(">"));
+}
+
+#[test]
+fn display_ask_answer_simplify() {
+    let module = module(vec![Exercise::Add, Exercise::Simplify]);
+
+    let rand_nums = [1, 4, 2, 1];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(2, lim),
+            1 => assert_eq!(8, lim),
+            2 => assert_eq!(5, lim),
+            _ => assert_eq!(4, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you simplify this fraction for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("9/18 = ?"), "{}", s);
+
+    assert_eq!(Outcome::Unsimplified, question.answer("9/18"));
+    assert_eq!(Outcome::Unsimplified, question.answer("3/6"));
+    assert_eq!(Outcome::Incorrect, question.answer("1/3"));
+    assert_eq!(Outcome::Correct, question.answer("1/2"));
+}
+
+#[test]
+fn parse_forms() {
+    fn fraction(numerator: u64, denominator: u64) -> Fraction {
+        Fraction::new(numerator, denominator)
+    }
+
+    assert_eq!(Ok((fraction(2, 1), true)), parse("2"));
+    assert_eq!(Ok((fraction(3, 4), true)), parse("3/4"));
+    assert_eq!(Ok((fraction(3, 4), true)), parse(" 3 / 4 "));
+    assert_eq!(Ok((fraction(6, 8), false)), parse("6/8"));
+    assert_eq!(Ok((fraction(4, 1), false)), parse("4/1"));
+    assert_eq!(Ok((fraction(0, 1), false)), parse("0/3"));
+    assert_eq!(Ok((fraction(3, 2), true)), parse("1 1/2"));
+    assert_eq!(Ok((fraction(3, 2), false)), parse("1 2/4"));
+    assert_eq!(Ok((fraction(5, 2), false)), parse("1 3/2"));
+    assert_eq!(Ok((fraction(1, 2), false)), parse("0 1/2"));
+    assert!(parse("").is_err());
+    assert!(parse("1/").is_err());
+    assert!(parse("/2").is_err());
+    assert!(parse("-1/2").is_err());
+    assert!(parse("1 1 1/2").is_err());
+    assert!(parse("1/2/3").is_err());
+}
+
+#[test]
+fn parse_huge() {
+    for answer in [
+        "18446744073709551615/1",
+        "1/18446744073709551615",
+        "18446744073709551615 1/2",
+        "1000001/2",
+        "99999999999999999999",
+    ] {
+        assert!(parse(answer).is_err(), "{answer}");
+    }
+    assert_eq!(
+        Err("'1000001/2' is too large; the numbers in a fraction cannot exceed 1000000".into()),
+        parse("1000001/2")
+    );
+    assert!(parse("1000000 999999/1000000").is_ok());
+
+    let module = module(vec![Exercise::Add, Exercise::Subtract, Exercise::Simplify]);
+    let mut rand = StdRand::default();
+    for _ in 0..10 {
+        let question = module.ask(&mut rand);
+        for answer in [
+            "18446744073709551615/1",
+            "18446744073709551615 1/2",
+            "4294967295 4294967295/4294967296",
+        ] {
+            assert!(
+                matches!(question.answer(answer), Outcome::Invalid(_)),
+                "{answer}"
+            );
+        }
+        question.answer("1000000 999999/1000000");
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Fractions::try_from(Config {
+        min_denominator: 1,
+        max_denominator: 10,
+        exercises: vec![Exercise::Add],
+    });
+    assert_eq!("min_denominator must be at least 2", module.err().unwrap());
+
+    let module = Fractions::try_from(Config {
+        min_denominator: 10,
+        max_denominator: 10,
+        exercises: vec![Exercise::Add],
+    });
+    assert_eq!(
+        "min_denominator must be less than max_denominator",
+        module.err().unwrap()
+    );
+
+    let module = Fractions::try_from(Config {
+        min_denominator: 2,
+        max_denominator: 1_001,
+        exercises: vec![Exercise::Add],
+    });
+    assert_eq!("max_denominator cannot exceed 1000", module.err().unwrap());
+
+    let module = Fractions::try_from(Config {
+        min_denominator: 2,
+        max_denominator: 10,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::fractions_1();
+    presets::fractions_2();
+}
@@ -0,0 +1,415 @@
+//! Questions on the perimeter and area of simple shapes, drawn as diagrams.
+
+use crate::topic::{choose, gcd, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The geometry module.
+pub struct Geometry {
+    config: Config,
+
+    /// The right triangles with whole sides in the configured range, as `(leg, leg, hypotenuse)`
+    /// with the shorter leg first.
+    triangles: Vec<(u32, u32, u32)>,
+}
+
+/// The shapes that may be drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rectangle,
+    Square,
+
+    /// A right triangle. Only triangles with whole sides (multiples of Pythagorean triples)
+    /// are drawn.
+    RightTriangle,
+}
+
+/// The measurements that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    Perimeter,
+    Area,
+}
+
+/// Configuration for [`Geometry`].
+pub struct Config {
+    /// The shortest side that will be drawn.
+    pub min_side: u32,
+
+    /// The longest side that will be drawn.
+    pub max_side: u32,
+
+    /// The unit of length used to label the sides; e.g., `cm`.
+    pub unit: String,
+
+    /// The shapes to choose from.
+    pub shapes: Vec<Shape>,
+
+    /// The measurements to choose from.
+    pub measures: Vec<Measure>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_and_enumerate().map(|_| ())
+    }
+
+    /// Validates the config, returning the right triangles that may be drawn.
+    fn validate_and_enumerate(&self) -> Result<Vec<(u32, u32, u32)>, String> {
+        const MAX_MAX_SIDE: u32 = 1_000;
+        if self.min_side == 0 {
+            return Err("min_side must be greater than zero".into());
+        }
+        if self.min_side >= self.max_side {
+            return Err("min_side must be less than max_side".into());
+        }
+        if self.max_side > MAX_MAX_SIDE {
+            return Err(format!("max_side cannot exceed {MAX_MAX_SIDE}"));
+        }
+        if self.shapes.is_empty() {
+            return Err("at least one shape must be specified".into());
+        }
+        if self.measures.is_empty() {
+            return Err("at least one measure must be specified".into());
+        }
+        let triangles = right_triangles(self.min_side, self.max_side);
+        if self.shapes.contains(&Shape::RightTriangle) && triangles.is_empty() {
+            return Err(
+                "no right triangles with whole sides lie between min_side and max_side".into(),
+            );
+        }
+        Ok(triangles)
+    }
+}
+
+impl TryFrom<Config> for Geometry {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        let triangles = config.validate_and_enumerate()?;
+        Ok(Self { config, triangles })
+    }
+}
+
+/// Enumerates the right triangles with whole sides in the range `min_side..max_side`, using
+/// Euclid's formula to generate the primitive triples.
+fn right_triangles(min_side: u32, max_side: u32) -> Vec<(u32, u32, u32)> {
+    let mut triangles = vec![];
+    let mut m = 2;
+    while m * m + 1 < max_side {
+        for n in 1..m {
+            if (m - n) % 2 == 1 && gcd(m, n) == 1 {
+                let (odd_leg, even_leg) = (m * m - n * n, 2 * m * n);
+                let (short, long) = (odd_leg.min(even_leg), odd_leg.max(even_leg));
+                let hypotenuse = m * m + n * n;
+                let mut multiple = 1;
+                while multiple * hypotenuse < max_side {
+                    if multiple * short >= min_side {
+                        triangles.push((multiple * short, multiple * long, multiple * hypotenuse));
+                    }
+                    multiple += 1;
+                }
+            }
+        }
+        m += 1;
+    }
+    triangles.sort_unstable();
+    triangles
+}
+
+impl Module for Geometry {
+    fn topic_name(&self) -> String {
+        String::from("geometry")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let shape = *choose(&self.config.shapes, rand);
+        let measure = *choose(&self.config.measures, rand);
+        let sides = self.config.min_side..self.config.max_side;
+        let figure = match shape {
+            Shape::Rectangle => Figure::Rectangle {
+                width: rand.next_range(sides.clone()),
+                height: rand.next_range(sides),
+            },
+            Shape::Square => Figure::Square {
+                side: rand.next_range(sides),
+            },
+            Shape::RightTriangle => {
+                let (height, base, hypotenuse) = *choose(&self.triangles, rand);
+                Figure::RightTriangle {
+                    base,
+                    height,
+                    hypotenuse,
+                }
+            }
+        };
+        Box::new(Measurement {
+            figure,
+            measure,
+            unit: self.config.unit.clone(),
+        })
+    }
+}
+
+/// A shape with the lengths of its sides.
+enum Figure {
+    Rectangle {
+        width: u32,
+        height: u32,
+    },
+    Square {
+        side: u32,
+    },
+    RightTriangle {
+        base: u32,
+        height: u32,
+        hypotenuse: u32,
+    },
+}
+
+impl Figure {
+    fn name(&self) -> &'static str {
+        match self {
+            Figure::Rectangle { .. } => "rectangle",
+            Figure::Square { .. } => "square",
+            Figure::RightTriangle { .. } => "right triangle",
+        }
+    }
+
+    fn perimeter(&self) -> u32 {
+        match *self {
+            Figure::Rectangle { width, height } => 2 * (width + height),
+            Figure::Square { side } => 4 * side,
+            Figure::RightTriangle {
+                base,
+                height,
+                hypotenuse,
+            } => base + height + hypotenuse,
+        }
+    }
+
+    fn area(&self) -> u32 {
+        match *self {
+            Figure::Rectangle { width, height } => width * height,
+            Figure::Square { side } => side * side,
+            Figure::RightTriangle { base, height, .. } => base * height / 2,
+        }
+    }
+
+    /// Draws the figure, labelling its sides. The drawing is scaled so that the longest side spans
+    /// a fixed number of columns; rows are about twice as tall as columns are wide, so vertical
+    /// extents are halved.
+    fn draw(&self, unit: &str) -> Vec<String> {
+        const COLUMNS: u32 = 20;
+        let label = |length: u32| {
+            if unit.is_empty() {
+                length.to_string()
+            } else {
+                format!("{length} {unit}")
+            }
+        };
+        let scale = |length: u32, longest: u32, span: u32, min: u32| {
+            ((length * span + longest / 2) / longest).max(min) as usize
+        };
+        match *self {
+            Figure::Rectangle { width, height } => draw_rectangle(
+                scale(width, width.max(height), COLUMNS, 4),
+                scale(height, width.max(height), COLUMNS / 4, 1),
+                &label(width),
+                &label(height),
+            ),
+            Figure::Square { side } => draw_rectangle(
+                COLUMNS as usize,
+                (COLUMNS / 4) as usize,
+                &label(side),
+                &label(side),
+            ),
+            Figure::RightTriangle {
+                base,
+                height,
+                hypotenuse,
+            } => draw_right_triangle(
+                scale(base, base.max(height), COLUMNS, 4),
+                scale(height, base.max(height), COLUMNS / 4, 2),
+                &label(base),
+                &label(height),
+                &label(hypotenuse),
+            ),
+        }
+    }
+}
+
+/// Draws a rectangle with the given inner dimensions, labelling its top and right sides.
+fn draw_rectangle(width: usize, height: usize, top: &str, right: &str) -> Vec<String> {
+    let border = format!("+{}+", "-".repeat(width));
+    let mut lines = vec![centre(top, width + 2), border.clone()];
+    for row in 0..height {
+        let mut line = format!("|{}|", " ".repeat(width));
+        if row == height / 2 {
+            line.push(' ');
+            line.push_str(right);
+        }
+        lines.push(line);
+    }
+    lines.push(border);
+    lines
+}
+
+/// Draws a right triangle with the right angle in its bottom-left corner, labelling its sides.
+/// The height must be at least 2.
+fn draw_right_triangle(
+    base: usize,
+    height: usize,
+    bottom: &str,
+    left: &str,
+    hypotenuse: &str,
+) -> Vec<String> {
+    let margin = left.len() + 1;
+    let mut lines = vec![];
+    for row in 0..height {
+        let mut line = if row == height / 2 {
+            format!("{left} ")
+        } else {
+            " ".repeat(margin)
+        };
+        line.push('|');
+        line.push_str(&" ".repeat(row * base / (height - 1)));
+        line.push('\\');
+        if row == height / 2 {
+            line.push_str("  ");
+            line.push_str(hypotenuse);
+        }
+        lines.push(line);
+    }
+    lines.push(format!("{}+{}", " ".repeat(margin), "-".repeat(base)));
+    lines.push(format!(
+        "{}{}",
+        " ".repeat(margin),
+        centre(bottom, base + 1)
+    ));
+    lines
+}
+
+/// Centres a label over the given number of columns, without trailing padding.
+fn centre(label: &str, columns: usize) -> String {
+    format!(
+        "{}{label}",
+        " ".repeat(columns.saturating_sub(label.len()) / 2)
+    )
+}
+
+/// Finding the perimeter or area of a figure.
+struct Measurement {
+    figure: Figure,
+    measure: Measure,
+    unit: String,
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let measure = match self.measure {
+            Measure::Perimeter => "perimeter",
+            Measure::Area => "area",
+        };
+        writeln!(
+            f,
+            "Can you find the {measure} of this {} for me.",
+            self.figure.name()
+        )?;
+        write!(f, "{}", self.figure.draw(&self.unit).join("\n"))
+    }
+}
+
+impl Question for Measurement {
+    fn answer(&self, answer: &str) -> Outcome {
+        let expected = match self.measure {
+            Measure::Perimeter => self.figure.perimeter(),
+            Measure::Area => self.figure.area(),
+        };
+        match parse(answer, &self.unit, self.measure) {
+            Ok(answer) => {
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Parses a whole number, optionally followed by the unit. Areas may be given in square units
+/// (`60 cm²`, `60 cm^2`, `60 cm2` or `60 sq cm`).
+fn parse(answer: &str, unit: &str, measure: Measure) -> Result<u32, String> {
+    let err = || {
+        let example = if unit.is_empty() {
+            String::new()
+        } else if measure == Measure::Area {
+            format!(" (e.g., '24 {unit}²')")
+        } else {
+            format!(" (e.g., '24 {unit}')")
+        };
+        format!(
+            "'{answer}' does not appear to be a valid measurement; \
+            write a whole number, optionally followed by the unit{example}"
+        )
+    };
+    let trimmed = answer.trim();
+    let split = trimmed
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split);
+    let suffix = suffix.trim();
+    let valid_suffix = suffix.is_empty()
+        || match measure {
+            Measure::Perimeter => suffix == unit,
+            Measure::Area => {
+                [format!("{unit}²"), format!("{unit}^2"), format!("{unit}2")]
+                    .contains(&suffix.to_owned())
+                    || suffix
+                        .strip_prefix("sq")
+                        .is_some_and(|rest| rest.trim_start_matches('.').trim() == unit)
+            }
+        };
+    if !valid_suffix || unit.is_empty() && !suffix.is_empty() {
+        return Err(err());
+    }
+    u32::from_str(number).map_err(|_| err())
+}
+
+pub mod presets {
+    use super::{Config, Geometry, Measure, Shape};
+
+    pub fn geometry_1() -> Geometry {
+        Config {
+            min_side: 1,
+            max_side: 13,
+            unit: "cm".into(),
+            shapes: vec![Shape::Rectangle, Shape::Square],
+            measures: vec![Measure::Perimeter, Measure::Area],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn geometry_2() -> Geometry {
+        Config {
+            min_side: 2,
+            max_side: 31,
+            unit: "m".into(),
+            shapes: vec![Shape::Rectangle, Shape::Square, Shape::RightTriangle],
+            measures: vec![Measure::Perimeter, Measure::Area],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
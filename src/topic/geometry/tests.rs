@@ -0,0 +1,225 @@
+use crate::topic::geometry::{presets, right_triangles, Config, Geometry, Measure, Shape};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+fn config(shapes: Vec<Shape>, measures: Vec<Measure>) -> Config {
+    Config {
+        min_side: 1,
+        max_side: 13,
+        unit: "cm".into(),
+        shapes,
+        measures,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::geometry_1();
+    assert_eq!("geometry", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_rectangle() {
+    let module = Geometry::try_from(config(
+        vec![Shape::Rectangle, Shape::Square],
+        vec![Measure::Perimeter, Measure::Area],
+    ))
+    .unwrap();
+
+    let rand_nums = [0, 1, 11, 4];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 | 1 => assert_eq!(2, lim),
+            _ => assert_eq!(12, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    let expected = [
+        "Can you find the area of this rectangle for me.",
+        "        12 cm",
+        "+--------------------+",
+        "|                    |",
+        "|                    | 5 cm",
+        "+--------------------+",
+    ]
+    .join("\n");
+    assert_eq!(expected, s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'60 m' does not appear to be a valid measurement; write a whole number, optionally followed by the unit (e.g., '24 cm²')"
+                .into()
+        ),
+        question.answer("60 m")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("34"));
+    for answer in ["60", "60 cm²", "60cm^2", "60 cm2", "60 sq cm", "60 sq. cm"] {
+        assert_eq!(Outcome::Correct, question.answer(answer), "{answer}");
+    }
+}
+
+#[test]
+fn display_ask_answer_square() {
+    let module = Geometry::try_from(config(vec![Shape::Square], vec![Measure::Perimeter])).unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(12, lim);
+        6
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you find the perimeter of this square for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("| 7 cm"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'28 cm²' does not appear to be a valid measurement; write a whole number, optionally followed by the unit (e.g., '24 cm')"
+                .into()
+        ),
+        question.answer("28 cm²")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("49"));
+    assert_eq!(Outcome::Correct, question.answer("28"));
+    assert_eq!(Outcome::Correct, question.answer("28 cm"));
+}
+
+#[test]
+fn display_ask_answer_right_triangle() {
+    let module = Geometry::try_from(Config {
+        min_side: 6,
+        max_side: 11,
+        unit: String::new(),
+        shapes: vec![Shape::RightTriangle],
+        measures: vec![Measure::Perimeter, Measure::Area],
+    })
+    .unwrap();
+
+    for (measure, expected_answer) in [(0, "24"), (1, "24")] {
+        let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+            assert_eq!(2, lim);
+            measure
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let expected = [
+            "  |\\",
+            "  |      \\",
+            "6 |             \\  10",
+            "  |                    \\",
+            "  +--------------------",
+            "            8",
+        ]
+        .join("\n");
+        assert!(s.ends_with(&expected), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+        assert_eq!(
+            Outcome::Invalid(
+                "'24 cm' does not appear to be a valid measurement; write a whole number, optionally followed by the unit"
+                    .into()
+            ),
+            question.answer("24 cm")
+        );
+    }
+}
+
+#[test]
+fn right_triangles_are_whole() {
+    let triangles = right_triangles(5, 31);
+    assert!(triangles.contains(&(5, 12, 13)));
+    assert!(triangles.contains(&(18, 24, 30)));
+    assert!(!triangles.contains(&(3, 4, 5)));
+    for (a, b, c) in triangles {
+        assert!(5 <= a && a <= b && c < 31, "{a}, {b}, {c}");
+        assert_eq!(a * a + b * b, c * c);
+    }
+
+    let module = presets::geometry_2();
+    let mut rand = StdRand::default();
+    for _ in 0..100 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.lines().count() >= 4, "{}", s);
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Geometry::try_from(Config {
+        min_side: 0,
+        max_side: 13,
+        unit: "cm".into(),
+        shapes: vec![Shape::Square],
+        measures: vec![Measure::Area],
+    });
+    assert_eq!("min_side must be greater than zero", module.err().unwrap());
+
+    let module = Geometry::try_from(Config {
+        min_side: 13,
+        max_side: 13,
+        unit: "cm".into(),
+        shapes: vec![Shape::Square],
+        measures: vec![Measure::Area],
+    });
+    assert_eq!("min_side must be less than max_side", module.err().unwrap());
+
+    let module = Geometry::try_from(Config {
+        min_side: 1,
+        max_side: 1_001,
+        unit: "cm".into(),
+        shapes: vec![Shape::Square],
+        measures: vec![Measure::Area],
+    });
+    assert_eq!("max_side cannot exceed 1000", module.err().unwrap());
+
+    let module = Geometry::try_from(Config {
+        min_side: 1,
+        max_side: 13,
+        unit: "cm".into(),
+        shapes: vec![],
+        measures: vec![Measure::Area],
+    });
+    assert_eq!(
+        "at least one shape must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Geometry::try_from(Config {
+        min_side: 1,
+        max_side: 13,
+        unit: "cm".into(),
+        shapes: vec![Shape::Square],
+        measures: vec![],
+    });
+    assert_eq!(
+        "at least one measure must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Geometry::try_from(Config {
+        min_side: 4,
+        max_side: 10,
+        unit: "cm".into(),
+        shapes: vec![Shape::RightTriangle],
+        measures: vec![Measure::Area],
+    });
+    assert_eq!(
+        "no right triangles with whole sides lie between min_side and max_side",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::geometry_1();
+    presets::geometry_2();
+}
@@ -0,0 +1,228 @@
+//! Word problems on money; e.g., working out the change from a purchase.
+
+use crate::topic::{decimals, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The money module.
+pub struct Money {
+    config: Config,
+}
+
+/// Configuration for [`Money`]. All amounts are given in minor units; e.g., cents.
+pub struct Config {
+    /// The symbol placed before amounts in the major unit; e.g., `$`.
+    pub symbol: String,
+
+    /// The symbol placed after amounts in the minor unit; e.g., `c`.
+    pub minor_symbol: String,
+
+    /// The number of minor units in the major unit; e.g., `100` cents in a dollar. Must be `1`,
+    /// `10`, `100` or `1000`.
+    pub minor_units: u32,
+
+    /// The smallest price that will be asked.
+    pub min_price: u32,
+
+    /// The largest price that will be asked.
+    pub max_price: u32,
+
+    /// Prices are multiples of this amount; e.g., `5` for prices rounded to five cents.
+    pub step: u32,
+
+    /// The notes (or coins) that may be tendered. The smallest note exceeding the price is
+    /// always used.
+    pub notes: Vec<u32>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_NOTE: u32 = 100_000_000;
+        if ![1, 10, 100, 1_000].contains(&self.minor_units) {
+            return Err("minor_units must be 1, 10, 100 or 1000".into());
+        }
+        if self.step == 0 {
+            return Err("step must be greater than zero".into());
+        }
+        if self.min_price == 0 {
+            return Err("min_price must be greater than zero".into());
+        }
+        if self.min_price >= self.max_price {
+            return Err("min_price must be less than max_price".into());
+        }
+        if self.prices().is_empty() {
+            return Err("no multiples of step lie between min_price and max_price".into());
+        }
+        let Some(&largest_note) = self.notes.iter().max() else {
+            return Err("at least one note must be specified".into());
+        };
+        if largest_note > MAX_NOTE {
+            return Err(format!("notes cannot exceed {MAX_NOTE}"));
+        }
+        if self.max_price > largest_note {
+            return Err("max_price cannot exceed the largest note".into());
+        }
+        Ok(())
+    }
+
+    /// The range of prices, as multiples of [`Config::step`].
+    fn prices(&self) -> Range<u32> {
+        self.min_price.div_ceil(self.step)..self.max_price.div_ceil(self.step)
+    }
+}
+
+impl TryFrom<Config> for Money {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Money {
+    fn topic_name(&self) -> String {
+        String::from("money")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let price = rand.next_range(self.config.prices()) * self.config.step;
+        let paid = self
+            .config
+            .notes
+            .iter()
+            .copied()
+            .filter(|&note| note > price)
+            .min()
+            .unwrap();
+        Box::new(Change {
+            currency: Currency {
+                symbol: self.config.symbol.clone(),
+                minor_symbol: self.config.minor_symbol.clone(),
+                places: self.config.minor_units.ilog10(),
+            },
+            price,
+            paid,
+        })
+    }
+}
+
+/// The notation for amounts of money.
+struct Currency {
+    symbol: String,
+    minor_symbol: String,
+
+    /// The number of digits after the decimal point in the major unit.
+    places: u32,
+}
+
+impl Currency {
+    /// Formats an amount given in minor units as an amount in the major unit; e.g., `$3.45`.
+    fn format(&self, amount: u32) -> String {
+        if self.places == 0 {
+            format!("{}{amount}", self.symbol)
+        } else {
+            format!(
+                "{}{}",
+                self.symbol,
+                decimals::format_scaled(amount.into(), self.places)
+            )
+        }
+    }
+
+    /// Determines whether an answer, given in either the major unit (`1.55` or `$1.55`) or the
+    /// minor unit (`155c`), matches the expected amount in minor units.
+    fn assess(&self, answer: &str, expected: u32) -> Result<bool, String> {
+        let err = || {
+            format!(
+                "'{answer}' does not appear to be a valid amount; write it as, e.g., '{}' or '{}{}'",
+                self.format(155),
+                155,
+                self.minor_symbol
+            )
+        };
+        let trimmed = answer.trim();
+        if self.places != 0 && !self.minor_symbol.is_empty() {
+            if let Some(minor) = trimmed.strip_suffix(self.minor_symbol.as_str()) {
+                let minor = u32::from_str(minor.trim()).map_err(|_| err())?;
+                return Ok(minor == expected);
+            }
+        }
+        let major = trimmed
+            .strip_prefix(self.symbol.as_str())
+            .unwrap_or(trimmed)
+            .trim();
+        let major = decimals::parse_scaled(major, self.places).map_err(|_| err())?;
+        Ok(major == u64::from(expected))
+    }
+}
+
+/// A purchase, for which the student must work out the change.
+struct Change {
+    currency: Currency,
+    price: u32,
+    paid: u32,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you work out the change for me.")?;
+        write!(
+            f,
+            "You pay {} for an item costing {}. How much change do you get?",
+            self.currency.format(self.paid),
+            self.currency.format(self.price)
+        )
+    }
+}
+
+impl Question for Change {
+    fn answer(&self, answer: &str) -> Outcome {
+        match self.currency.assess(answer, self.paid - self.price) {
+            Ok(true) => Outcome::Correct,
+            Ok(false) => Outcome::Incorrect,
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Money};
+
+    pub fn money_1() -> Money {
+        Config {
+            symbol: "$".into(),
+            minor_symbol: "c".into(),
+            minor_units: 100,
+            min_price: 5,
+            max_price: 1_000,
+            step: 5,
+            notes: vec![500, 1_000],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn money_2() -> Money {
+        Config {
+            symbol: "$".into(),
+            minor_symbol: "c".into(),
+            minor_units: 100,
+            min_price: 1,
+            max_price: 10_000,
+            step: 1,
+            notes: vec![500, 1_000, 2_000, 5_000, 10_000],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,225 @@
+use crate::topic::money::{presets, Config, Money};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+fn config() -> Config {
+    Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 1,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![2_000, 500, 1_000],
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::money_1();
+    assert_eq!("money", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer() {
+    let module = Money::try_from(config()).unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(1_999, lim);
+        344
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you work out the change for me."), "{}", s);
+    assert!(
+        s.contains("You pay $5.00 for an item costing $3.45. How much change do you get?"),
+        "{}",
+        s
+    );
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'foo' does not appear to be a valid amount; write it as, e.g., '$1.55' or '155c'"
+                .into()
+        ),
+        question.answer("foo")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("1.45"));
+    assert_eq!(
+        Outcome::Invalid(
+            "'1.555' does not appear to be a valid amount; write it as, e.g., '$1.55' or '155c'"
+                .into()
+        ),
+        question.answer("1.555")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("155"));
+    assert_eq!(Outcome::Correct, question.answer("1.55"));
+    assert_eq!(Outcome::Correct, question.answer("$1.55"));
+    assert_eq!(Outcome::Correct, question.answer("$1.550"));
+    assert_eq!(Outcome::Correct, question.answer("$ 1,55"));
+    assert_eq!(Outcome::Correct, question.answer("155c"));
+    assert_eq!(Outcome::Correct, question.answer("155 c"));
+}
+
+#[test]
+fn display_ask_answer_step() {
+    let module = Money::try_from(Config {
+        min_price: 12,
+        step: 5,
+        ..config()
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(397, lim);
+        196
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("You pay $10.00 for an item costing $9.95."),
+        "{}",
+        s
+    );
+    assert_eq!(Outcome::Correct, question.answer("$0.05"));
+    assert_eq!(Outcome::Correct, question.answer(".05"));
+    assert_eq!(Outcome::Correct, question.answer("5c"));
+}
+
+#[test]
+fn display_ask_answer_whole_units() {
+    let module = Money::try_from(Config {
+        symbol: "¥".into(),
+        minor_symbol: String::new(),
+        minor_units: 1,
+        min_price: 10,
+        max_price: 1_000,
+        step: 10,
+        notes: vec![1_000],
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, _| 33);
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("You pay ¥1000 for an item costing ¥340."),
+        "{}",
+        s
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("650"));
+    assert_eq!(Outcome::Correct, question.answer("660"));
+    assert_eq!(Outcome::Correct, question.answer("¥660"));
+}
+
+#[test]
+fn invalid_config() {
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 50,
+        min_price: 1,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!(
+        "minor_units must be 1, 10, 100 or 1000",
+        module.err().unwrap()
+    );
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 1,
+        max_price: 2_000,
+        step: 0,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!("step must be greater than zero", module.err().unwrap());
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 0,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!("min_price must be greater than zero", module.err().unwrap());
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 2_000,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!(
+        "min_price must be less than max_price",
+        module.err().unwrap()
+    );
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 11,
+        max_price: 20,
+        step: 10,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!(
+        "no multiples of step lie between min_price and max_price",
+        module.err().unwrap()
+    );
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 1,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![],
+    });
+    assert_eq!("at least one note must be specified", module.err().unwrap());
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 1,
+        max_price: 2_000,
+        step: 1,
+        notes: vec![100_000_001],
+    });
+    assert_eq!("notes cannot exceed 100000000", module.err().unwrap());
+
+    let module = Money::try_from(Config {
+        symbol: "$".into(),
+        minor_symbol: "c".into(),
+        minor_units: 100,
+        min_price: 1,
+        max_price: 2_001,
+        step: 1,
+        notes: vec![2_000, 500, 1_000],
+    });
+    assert_eq!(
+        "max_price cannot exceed the largest note",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::money_1();
+    presets::money_2();
+}
@@ -0,0 +1,142 @@
+//! Questions on multiplication.
+
+use crate::topic::{Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The multiplication module.
+pub struct Multiplication {
+    config: Config,
+}
+
+/// Configuration for [`Multiplication`].
+pub struct Config {
+    /// The smallest number that will be asked on the left-hand side.
+    pub min_lhs: u32,
+
+    /// The largest number that will be asked on the left-hand side.
+    pub max_lhs: u32,
+
+    /// The smallest number that will be asked on the right-hand side.
+    pub min_rhs: u32,
+
+    /// The largest number that will be asked on the right-hand side.
+    pub max_rhs: u32,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_lhs >= self.max_lhs {
+            return Err("min_lhs must be less than max_lhs".into());
+        }
+        if self.min_rhs >= self.max_rhs {
+            return Err("min_rhs must be less than max_rhs".into());
+        }
+        if self.max_lhs.checked_mul(self.max_rhs).is_none() {
+            return Err(format!(
+                "the product of max_lhs and max_rhs cannot exceed {}",
+                u32::MAX
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Multiplication {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Multiplication {
+    fn topic_name(&self) -> String {
+        String::from("multiplication")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let lhs = rand.next_range(self.config.min_lhs..self.config.max_lhs);
+        let rhs = rand.next_range(self.config.min_rhs..self.config.max_rhs);
+        Box::new(Product { lhs, rhs })
+    }
+}
+
+pub(crate) struct Product {
+    pub(crate) lhs: u32,
+    pub(crate) rhs: u32,
+}
+
+impl Display for Product {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you multiply these two numbers for me.")?;
+        write!(f, "{} × {} = ?", self.lhs, self.rhs)
+    }
+}
+
+impl Question for Product {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok(answer) => {
+                let expected = self.lhs * self.rhs;
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+fn parse(answer: &str) -> Result<u32, String> {
+    u32::from_str(answer)
+        .map_err(|_| format!("'{answer}' does not appear to be a valid natural number"))
+}
+
+pub mod presets {
+    use super::{Config, Multiplication};
+
+    pub fn multiplication_1() -> Multiplication {
+        Config {
+            min_lhs: 0,
+            max_lhs: 10,
+            min_rhs: 0,
+            max_rhs: 10,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn multiplication_2() -> Multiplication {
+        Config {
+            min_lhs: 0,
+            max_lhs: 100,
+            min_rhs: 0,
+            max_rhs: 13,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn multiplication_3() -> Multiplication {
+        Config {
+            min_lhs: 0,
+            max_lhs: 10_000,
+            min_rhs: 0,
+            max_rhs: 1_000,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
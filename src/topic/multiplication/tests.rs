@@ -0,0 +1,88 @@
+use crate::topic::multiplication::{presets, Config, Multiplication};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::multiplication_1();
+    assert_eq!("multiplication", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer() {
+    let module = Multiplication::try_from(Config {
+        min_lhs: 10,
+        max_lhs: 30,
+        min_rhs: 2,
+        max_rhs: 12,
+    })
+    .unwrap();
+
+    let rand_nums = [3, 5];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(20, lim);
+        } else {
+            assert_eq!(10, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you multiply these two numbers for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("13 × 7"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'foo' does not appear to be a valid natural number".into()),
+        question.answer("foo")
+    );
+    assert_eq!(
+        Outcome::Invalid("'-1' does not appear to be a valid natural number".into()),
+        question.answer("-1")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("90"));
+    assert_eq!(Outcome::Incorrect, question.answer("92"));
+    assert_eq!(Outcome::Correct, question.answer("91"));
+}
+
+#[test]
+fn invalid_config() {
+    let module = Multiplication::try_from(Config {
+        min_lhs: 10,
+        max_lhs: 10,
+        min_rhs: 0,
+        max_rhs: 10,
+    });
+    assert_eq!("min_lhs must be less than max_lhs", module.err().unwrap());
+
+    let module = Multiplication::try_from(Config {
+        min_lhs: 0,
+        max_lhs: 10,
+        min_rhs: 10,
+        max_rhs: 10,
+    });
+    assert_eq!("min_rhs must be less than max_rhs", module.err().unwrap());
+
+    let module = Multiplication::try_from(Config {
+        min_lhs: 0,
+        max_lhs: 1 << 16,
+        min_rhs: 0,
+        max_rhs: 1 << 16,
+    });
+    assert_eq!(
+        format!("the product of max_lhs and max_rhs cannot exceed {}", u32::MAX),
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::multiplication_1();
+    presets::multiplication_2();
+    presets::multiplication_3();
+}
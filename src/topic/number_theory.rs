@@ -0,0 +1,307 @@
+//! Questions on factors, multiples and primes.
+
+use crate::topic::{choose, gcd, Module, Outcome, Question};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The number theory module.
+pub struct NumberTheory {
+    config: Config,
+
+    /// The primes in the configured range.
+    primes: Vec<u32>,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Deciding whether a number is prime. About half of the numbers asked are prime.
+    Prime,
+
+    /// Listing all the factors of a number, in any order.
+    Factors,
+
+    /// Finding the greatest common divisor of two numbers, which always share a factor.
+    Gcd,
+
+    /// Finding the lowest common multiple of two numbers.
+    Lcm,
+}
+
+/// Configuration for [`NumberTheory`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+/// The largest common factor deliberately shared by the numbers in [`Exercise::Gcd`].
+const MAX_COMMON_FACTOR: u32 = 12;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_and_sieve().map(|_| ())
+    }
+
+    /// Validates the config, returning the primes in the configured range.
+    fn validate_and_sieve(&self) -> Result<Vec<u32>, String> {
+        const MAX_MAX_VAL: u32 = 10_000;
+        if self.min_val < 2 {
+            return Err("min_val must be at least 2".into());
+        }
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        let primes = (self.min_val..self.max_val)
+            .filter(|&n| is_prime(n))
+            .collect::<Vec<_>>();
+        if self.exercises.contains(&Exercise::Prime) && primes.is_empty() {
+            return Err("no primes lie between min_val and max_val".into());
+        }
+        if self.exercises.contains(&Exercise::Gcd)
+            && self.max_val - self.min_val < MAX_COMMON_FACTOR
+        {
+            return Err(format!(
+                "max_val must exceed min_val by at least {MAX_COMMON_FACTOR} to ask for the GCD"
+            ));
+        }
+        Ok(primes)
+    }
+}
+
+impl TryFrom<Config> for NumberTheory {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        let primes = config.validate_and_sieve()?;
+        Ok(Self { config, primes })
+    }
+}
+
+fn is_prime(n: u32) -> bool {
+    n >= 2 && (2..=n.isqrt()).all(|d| !n.is_multiple_of(d))
+}
+
+impl Module for NumberTheory {
+    fn topic_name(&self) -> String {
+        String::from("number theory")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let range = self.config.min_val..self.config.max_val;
+        match exercise {
+            Exercise::Prime => {
+                let number = if rand.next_range(0..2) == 0 {
+                    *choose(&self.primes, rand)
+                } else {
+                    rand.next_range(range)
+                };
+                Box::new(Primality { number })
+            }
+            Exercise::Factors => Box::new(Factors {
+                number: rand.next_range(range),
+            }),
+            Exercise::Gcd => {
+                let factor = rand.next_range(2..MAX_COMMON_FACTOR + 1);
+                let multiples =
+                    self.config.min_val.div_ceil(factor)..self.config.max_val.div_ceil(factor);
+                let lhs = rand.next_range(multiples.clone()) * factor;
+                let rhs = rand.next_range(multiples) * factor;
+                Box::new(Common {
+                    lhs,
+                    rhs,
+                    lcm: false,
+                })
+            }
+            Exercise::Lcm => Box::new(Common {
+                lhs: rand.next_range(range.clone()),
+                rhs: rand.next_range(range),
+                lcm: true,
+            }),
+        }
+    }
+}
+
+/// Deciding whether a number is prime.
+struct Primality {
+    number: u32,
+}
+
+impl Display for Primality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you tell me whether this number is prime. Answer yes or no."
+        )?;
+        write!(f, "Is {} prime?", self.number)
+    }
+}
+
+impl Question for Primality {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_yes_no(answer) {
+            Ok(answer) => {
+                if answer == is_prime(self.number) {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Listing the factors of a number.
+struct Factors {
+    number: u32,
+}
+
+impl Display for Factors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you list all the factors of this number for me. Separate them with commas."
+        )?;
+        write!(f, "What are the factors of {}?", self.number)
+    }
+}
+
+impl Question for Factors {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_set(answer) {
+            Ok(answer) => {
+                let expected = (1..=self.number)
+                    .filter(|&d| self.number.is_multiple_of(d))
+                    .collect::<BTreeSet<_>>();
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Finding the greatest common divisor or the lowest common multiple of two numbers.
+struct Common {
+    lhs: u32,
+    rhs: u32,
+    lcm: bool,
+}
+
+impl Display for Common {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.lcm {
+            writeln!(f, "Can you find the lowest common multiple for me.")?;
+            write!(f, "What is the LCM of {} and {}?", self.lhs, self.rhs)
+        } else {
+            writeln!(f, "Can you find the greatest common divisor for me.")?;
+            write!(f, "What is the GCD of {} and {}?", self.lhs, self.rhs)
+        }
+    }
+}
+
+impl Question for Common {
+    fn answer(&self, answer: &str) -> Outcome {
+        let gcd = gcd(self.lhs, self.rhs);
+        let expected = if self.lcm {
+            self.lhs / gcd * self.rhs
+        } else {
+            gcd
+        };
+        match u32::from_str(answer.trim()) {
+            Ok(answer) => {
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(_) => Outcome::Invalid(format!(
+                "'{answer}' does not appear to be a valid natural number"
+            )),
+        }
+    }
+}
+
+/// Parses `yes` or `no` (or `y` or `n`), ignoring case.
+fn parse_yes_no(answer: &str) -> Result<bool, String> {
+    match answer.trim().to_lowercase().as_str() {
+        "yes" | "y" => Ok(true),
+        "no" | "n" => Ok(false),
+        _ => Err(format!("'{answer}' does not appear to be either yes or no")),
+    }
+}
+
+/// Parses a list of natural numbers, separated by commas, spaces or `and`, as a set. The order
+/// of the numbers is immaterial, as is the repetition of a number.
+pub(crate) fn parse_set(answer: &str) -> Result<BTreeSet<u32>, String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid list of natural numbers; \
+            separate the numbers with commas (e.g., '1, 2, 4')"
+        )
+    };
+    let set = answer
+        .split(|ch: char| ch == ',' || ch.is_whitespace())
+        .filter(|item| !item.is_empty() && !item.eq_ignore_ascii_case("and"))
+        .map(|item| u32::from_str(item).map_err(|_| err()))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    if set.is_empty() {
+        return Err(err());
+    }
+    Ok(set)
+}
+
+pub mod presets {
+    use super::{Config, Exercise, NumberTheory};
+
+    pub fn number_theory_1() -> NumberTheory {
+        Config {
+            min_val: 2,
+            max_val: 50,
+            exercises: vec![Exercise::Prime, Exercise::Factors],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn number_theory_2() -> NumberTheory {
+        Config {
+            min_val: 2,
+            max_val: 100,
+            exercises: vec![
+                Exercise::Prime,
+                Exercise::Factors,
+                Exercise::Gcd,
+                Exercise::Lcm,
+            ],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,182 @@
+use crate::topic::number_theory::{parse_set, presets, Config, Exercise, NumberTheory};
+use crate::topic::{Module, Outcome};
+use std::collections::BTreeSet;
+use tinyrand_alloc::Mock;
+
+fn config(exercises: Vec<Exercise>) -> Config {
+    Config {
+        min_val: 2,
+        max_val: 100,
+        exercises,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::number_theory_1();
+    assert_eq!("number theory", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_prime() {
+    let module = NumberTheory::try_from(config(vec![Exercise::Prime])).unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        ([1, 89], "Is 91 prime?", "no"),
+        ([0, 24], "Is 97 prime?", "yes"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            match (surrogate.state().next_lim_u128_invocations(), rand_nums[0]) {
+                (0, _) => assert_eq!(2, lim),
+                (_, 0) => assert_eq!(25, lim),
+                _ => assert_eq!(98, lim),
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(
+            s.contains("Can you tell me whether this number is prime."),
+            "{}",
+            s
+        );
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(
+            Outcome::Invalid("'maybe' does not appear to be either yes or no".into()),
+            question.answer("maybe")
+        );
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_factors() {
+    let module = NumberTheory::try_from(config(vec![Exercise::Prime, Exercise::Factors])).unwrap();
+
+    let rand_nums = [1, 22];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you list all the factors of this number for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("What are the factors of 24?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'1, 2, three' does not appear to be a valid list of natural numbers; separate the numbers with commas (e.g., '1, 2, 4')"
+                .into()
+        ),
+        question.answer("1, 2, three")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("2, 3, 4, 6, 8, 12"));
+    assert_eq!(
+        Outcome::Incorrect,
+        question.answer("1, 2, 3, 4, 5, 6, 8, 12, 24")
+    );
+    assert_eq!(
+        Outcome::Correct,
+        question.answer("1, 2, 3, 4, 6, 8, 12, 24")
+    );
+    assert_eq!(Outcome::Correct, question.answer("24, 12 8 6,4,3, 2 and 1"));
+}
+
+#[test]
+fn display_ask_answer_gcd_lcm() {
+    let module = NumberTheory::try_from(config(vec![Exercise::Gcd, Exercise::Lcm])).unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        ([0, 5, 11, 4], "What is the GCD of 84 and 35?", "7"),
+        ([1, 4, 6, 0], "What is the LCM of 6 and 8?", "24"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(
+            Outcome::Invalid("'x' does not appear to be a valid natural number".into()),
+            question.answer("x")
+        );
+        assert_eq!(Outcome::Incorrect, question.answer("1"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn parse_set_ignores_order_and_repetition() {
+    assert_eq!(Ok(BTreeSet::from([1, 2, 3])), parse_set("3, 2, 1"));
+    assert_eq!(Ok(BTreeSet::from([1, 2, 3])), parse_set("1 2 2 3"));
+    assert_eq!(Ok(BTreeSet::from([5])), parse_set(" 5 "));
+    assert!(parse_set("").is_err());
+    assert!(parse_set(", and").is_err());
+    assert!(parse_set("1, -2").is_err());
+}
+
+#[test]
+fn invalid_config() {
+    let module = NumberTheory::try_from(Config {
+        min_val: 1,
+        max_val: 100,
+        exercises: vec![Exercise::Factors],
+    });
+    assert_eq!("min_val must be at least 2", module.err().unwrap());
+
+    let module = NumberTheory::try_from(Config {
+        min_val: 2,
+        max_val: 2,
+        exercises: vec![Exercise::Factors],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = NumberTheory::try_from(Config {
+        min_val: 2,
+        max_val: 10_001,
+        exercises: vec![Exercise::Factors],
+    });
+    assert_eq!("max_val cannot exceed 10000", module.err().unwrap());
+
+    let module = NumberTheory::try_from(Config {
+        min_val: 2,
+        max_val: 100,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+
+    let module = NumberTheory::try_from(Config {
+        min_val: 24,
+        max_val: 29,
+        exercises: vec![Exercise::Prime],
+    });
+    assert_eq!(
+        "no primes lie between min_val and max_val",
+        module.err().unwrap()
+    );
+
+    let module = NumberTheory::try_from(Config {
+        min_val: 20,
+        max_val: 31,
+        exercises: vec![Exercise::Gcd],
+    });
+    assert_eq!(
+        "max_val must exceed min_val by at least 12 to ask for the GCD",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::number_theory_1();
+    presets::number_theory_2();
+}
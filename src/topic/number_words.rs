@@ -0,0 +1,335 @@
+//! Questions on reading and writing numbers in words; e.g., `347` as "three hundred and
+//! forty-seven".
+
+use crate::profile::sanitise;
+use crate::topic::{choose, place_value, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The number words module.
+pub struct NumberWords {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Writing a number given in digits in words; e.g., `347` as "three hundred and forty-seven".
+    ToWords,
+
+    /// Writing a number given in words in digits.
+    FromWords,
+}
+
+/// Configuration for [`NumberWords`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 1_000_000;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for NumberWords {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for NumberWords {
+    fn topic_name(&self) -> String {
+        String::from("number words")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let number = rand.next_range(self.config.min_val..self.config.max_val);
+        match exercise {
+            Exercise::ToWords => Box::new(ToWords { number }),
+            Exercise::FromWords => Box::new(FromWords { number }),
+        }
+    }
+}
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Writes a number below one million in words, in the British style; e.g., `1005` as
+/// "one thousand and five".
+pub(crate) fn to_words(number: u32) -> String {
+    if number == 0 {
+        return String::from(UNITS[0]);
+    }
+    let (thousands, rest) = (number / 1_000, number % 1_000);
+    let mut words = String::new();
+    if thousands > 0 {
+        words.push_str(&below_thousand(thousands));
+        words.push_str(" thousand");
+        if rest > 0 {
+            words.push_str(if rest < 100 { " and " } else { " " });
+        }
+    }
+    if rest > 0 {
+        words.push_str(&below_thousand(rest));
+    }
+    words
+}
+
+fn below_thousand(number: u32) -> String {
+    let (hundreds, rest) = (number / 100, number % 100);
+    match (hundreds, rest) {
+        (0, _) => below_hundred(rest),
+        (_, 0) => format!("{} hundred", UNITS[hundreds as usize]),
+        _ => format!(
+            "{} hundred and {}",
+            UNITS[hundreds as usize],
+            below_hundred(rest)
+        ),
+    }
+}
+
+fn below_hundred(number: u32) -> String {
+    if number < 20 {
+        String::from(UNITS[number as usize])
+    } else if number.is_multiple_of(10) {
+        String::from(TENS[number as usize / 10])
+    } else {
+        format!(
+            "{}-{}",
+            TENS[number as usize / 10],
+            UNITS[number as usize % 10]
+        )
+    }
+}
+
+/// A word that may appear in a number written in words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    Number(u32),
+    Hundred,
+    Thousand,
+    And,
+}
+
+/// Splits a slug (as obtained from [`sanitise`]) into words. Longer words are tried first, so
+/// that "seventeen" is not read as "seven" followed by a stray "teen", and "thousand" is not
+/// read as ending in "and".
+fn split_words(mut slug: &str) -> Option<Vec<Word>> {
+    let mut vocabulary = UNITS
+        .iter()
+        .enumerate()
+        .map(|(value, &name)| (name, Word::Number(u32::try_from(value).unwrap())))
+        .chain(
+            TENS.iter()
+                .enumerate()
+                .skip(2)
+                .map(|(tens, &name)| (name, Word::Number(u32::try_from(tens * 10).unwrap()))),
+        )
+        .chain([
+            ("hundred", Word::Hundred),
+            ("thousand", Word::Thousand),
+            ("and", Word::And),
+        ])
+        .collect::<Vec<_>>();
+    vocabulary.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+
+    let mut words = vec![];
+    while !slug.is_empty() {
+        let &(name, word) = vocabulary.iter().find(|(name, _)| slug.starts_with(name))?;
+        words.push(word);
+        slug = &slug[name.len()..];
+    }
+    Some(words)
+}
+
+/// Parses a number written in words. Case, hyphens, spacing, accents and the word "and" are
+/// disregarded, so "Three hundred and forty-seven" and "three hundred forty seven" are read
+/// alike. The words must nonetheless be in a sensible order; e.g., "forty twenty" is rejected.
+pub(crate) fn parse_words(answer: &str) -> Result<u32, String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a number written in words \
+            (e.g., 'three hundred and forty-seven')"
+        )
+    };
+    let words = split_words(&sanitise(answer)).ok_or_else(err)?;
+    let words = words
+        .into_iter()
+        .filter(|&word| word != Word::And)
+        .collect::<Vec<_>>();
+    if words == [Word::Number(0)] {
+        return Ok(0);
+    }
+
+    let (mut total, mut current, mut group) = (0, 0, 0u32);
+    let mut seen_thousand = false;
+    for word in &words {
+        match *word {
+            Word::Number(0) => return Err(err()),
+            Word::Number(value) => {
+                let allowed = if value < 10 {
+                    group == 0 || (group >= 20 && group.is_multiple_of(10))
+                } else {
+                    group == 0
+                };
+                if !allowed {
+                    return Err(err());
+                }
+                group += value;
+                current += value;
+            }
+            Word::Hundred => {
+                if group == 0 || group > 9 || current != group {
+                    return Err(err());
+                }
+                current *= 100;
+                group = 0;
+            }
+            Word::Thousand => {
+                if current == 0 || seen_thousand {
+                    return Err(err());
+                }
+                total = current * 1_000;
+                current = 0;
+                group = 0;
+                seen_thousand = true;
+            }
+            Word::And => {}
+        }
+    }
+    if words.is_empty() {
+        return Err(err());
+    }
+    Ok(total + current)
+}
+
+/// Writing a number given in digits in words.
+struct ToWords {
+    number: u32,
+}
+
+impl Display for ToWords {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you write this number in words for me.")?;
+        write!(f, "{}", place_value::format_thousands(self.number))
+    }
+}
+
+impl Question for ToWords {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_words(answer) {
+            Ok(answer) => {
+                if answer == self.number {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Writing a number given in words in digits.
+struct FromWords {
+    number: u32,
+}
+
+impl Display for FromWords {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you write this number in digits for me.")?;
+        write!(f, "{}", to_words(self.number))
+    }
+}
+
+impl Question for FromWords {
+    fn answer(&self, answer: &str) -> Outcome {
+        match place_value::parse(answer) {
+            Ok(answer) => {
+                if answer == self.number {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Exercise, NumberWords};
+
+    pub fn number_words_1() -> NumberWords {
+        Config {
+            min_val: 0,
+            max_val: 1_000,
+            exercises: vec![Exercise::ToWords, Exercise::FromWords],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn number_words_2() -> NumberWords {
+        Config {
+            min_val: 1_000,
+            max_val: 1_000_000,
+            exercises: vec![Exercise::ToWords, Exercise::FromWords],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
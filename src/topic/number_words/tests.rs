@@ -0,0 +1,186 @@
+use crate::topic::number_words::{parse_words, presets, to_words, Config, Exercise, NumberWords};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::number_words_1();
+    assert_eq!("number words", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_to_words() {
+    let module = NumberWords::try_from(Config {
+        min_val: 0,
+        max_val: 1_000_000,
+        exercises: vec![Exercise::ToWords],
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(1_000_000, lim);
+        21_302
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you write this number in words for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("21,302"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'21302' does not appear to be a number written in words (e.g., 'three hundred and forty-seven')"
+                .into()
+        ),
+        question.answer("21302")
+    );
+    assert_eq!(
+        Outcome::Incorrect,
+        question.answer("twenty-one thousand three hundred and twenty")
+    );
+    for answer in [
+        "twenty-one thousand three hundred and two",
+        "Twenty One Thousand, Three Hundred And Two",
+        "twenty-one thousand three hundred two",
+        " twentyone thousand threehundred and two ",
+        "twenty‐one thousand three hundred and two",
+    ] {
+        assert_eq!(Outcome::Correct, question.answer(answer), "{answer}");
+    }
+}
+
+#[test]
+fn display_ask_answer_from_words() {
+    let module = NumberWords::try_from(Config {
+        min_val: 0,
+        max_val: 1_000,
+        exercises: vec![Exercise::ToWords, Exercise::FromWords],
+    })
+    .unwrap();
+
+    let rand_nums = [1, 347];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(2, lim),
+            _ => assert_eq!(1_000, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you write this number in digits for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("three hundred and forty-seven"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'three' does not appear to be a valid natural number".into()),
+        question.answer("three")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("374"));
+    assert_eq!(Outcome::Correct, question.answer(" 347 "));
+}
+
+#[test]
+fn to_words_examples() {
+    for (number, expected) in [
+        (0, "zero"),
+        (7, "seven"),
+        (13, "thirteen"),
+        (40, "forty"),
+        (99, "ninety-nine"),
+        (100, "one hundred"),
+        (110, "one hundred and ten"),
+        (347, "three hundred and forty-seven"),
+        (1_000, "one thousand"),
+        (1_005, "one thousand and five"),
+        (1_200, "one thousand two hundred"),
+        (21_302, "twenty-one thousand three hundred and two"),
+        (
+            999_999,
+            "nine hundred and ninety-nine thousand nine hundred and ninety-nine",
+        ),
+    ] {
+        assert_eq!(expected, to_words(number), "{number}");
+    }
+}
+
+#[test]
+fn parse_words_rejects_malformed() {
+    for answer in [
+        "",
+        "and",
+        "forty twenty",
+        "seven forty",
+        "twelve three",
+        "twelve hundred",
+        "zero zero",
+        "one zero",
+        "hundred",
+        "thousand",
+        "one thousand one thousand",
+        "one hundred hundred",
+        "fourty",
+        "seven cats",
+    ] {
+        assert!(parse_words(answer).is_err(), "{answer}");
+    }
+}
+
+#[test]
+fn parse_words_inverts_to_words() {
+    for number in (0..20_000).chain(990_000..1_000_000) {
+        assert_eq!(Ok(number), parse_words(&to_words(number)), "{number}");
+    }
+}
+
+#[test]
+fn ask_within_range() {
+    let module = presets::number_words_2();
+    let mut rand = StdRand::default();
+    for _ in 0..100 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let body = s.lines().nth(1).unwrap();
+        let number = match parse_words(body) {
+            Ok(number) => number,
+            Err(_) => body.replace(',', "").parse().unwrap(),
+        };
+        assert!((1_000..1_000_000).contains(&number), "{s}");
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = NumberWords::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        exercises: vec![Exercise::ToWords],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = NumberWords::try_from(Config {
+        min_val: 0,
+        max_val: 1_000_001,
+        exercises: vec![Exercise::ToWords],
+    });
+    assert_eq!("max_val cannot exceed 1000000", module.err().unwrap());
+
+    let module = NumberWords::try_from(Config {
+        min_val: 0,
+        max_val: 100,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
@@ -0,0 +1,312 @@
+//! Questions on percentages and ratios.
+
+use crate::topic::{choose, gcd, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The percentages module.
+pub struct Percentages {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked. The answers are always whole numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Taking a percentage of an amount; e.g., `What is 15% of 240?`.
+    Percentage,
+
+    /// Finding the percentage change between two amounts; e.g., `from 80 to 100` is `25%`.
+    Change,
+
+    /// Sharing an amount in a ratio of two parts; e.g., `35` in the ratio `2:5` is `10:25`.
+    Ratio,
+}
+
+/// Configuration for [`Percentages`].
+pub struct Config {
+    /// The smallest amount that will be asked.
+    pub min_val: u32,
+
+    /// The largest amount that will be asked.
+    pub max_val: u32,
+
+    /// The percentages that will be asked are multiples of this. Must divide 100.
+    pub percent_step: u32,
+
+    /// The largest part of a ratio.
+    pub max_part: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+const HUNDRED: u32 = 100;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 1_000_000;
+        const MAX_MAX_PART: u32 = 50;
+        if self.min_val == 0 {
+            return Err("min_val must be greater than zero".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+
+        // Every percentage and ratio requires an amount that is a multiple of at most 100.
+        if self.max_val - self.min_val < HUNDRED {
+            return Err(format!("max_val must exceed min_val by at least {HUNDRED}"));
+        }
+        if self.percent_step == 0 || !HUNDRED.is_multiple_of(self.percent_step) {
+            return Err(format!("percent_step must be a divisor of {HUNDRED}"));
+        }
+        if self.max_part < 2 || self.max_part > MAX_MAX_PART {
+            return Err(format!("max_part must be between 2 and {MAX_MAX_PART}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+
+    /// The multiples of `unit` that lie in the configured range of amounts, divided by `unit`.
+    fn multiples(&self, unit: u32) -> Range<u32> {
+        self.min_val.div_ceil(unit)..self.max_val.div_ceil(unit)
+    }
+}
+
+impl TryFrom<Config> for Percentages {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Percentages {
+    /// Draws a percentage, alongside an amount of which that percentage is whole.
+    fn percentage_of_amount(&self, rand: &mut dyn RandRange<u32>) -> (u32, u32) {
+        let step = self.config.percent_step;
+        let percent = rand.next_range(1..HUNDRED / step + 1) * step;
+        let unit = HUNDRED / gcd(percent, HUNDRED);
+        let amount = rand.next_range(self.config.multiples(unit)) * unit;
+        (percent, amount)
+    }
+}
+
+impl Module for Percentages {
+    fn topic_name(&self) -> String {
+        String::from("percentages")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        match exercise {
+            Exercise::Percentage => {
+                let (percent, amount) = self.percentage_of_amount(rand);
+                Box::new(Percentage { percent, amount })
+            }
+            Exercise::Change => {
+                let (percent, from) = self.percentage_of_amount(rand);
+                let change = from * percent / HUNDRED;
+                let decrease = percent < HUNDRED && rand.next_range(0..2) == 1;
+                let to = if decrease {
+                    from - change
+                } else {
+                    from + change
+                };
+                Box::new(Change { from, to })
+            }
+            Exercise::Ratio => {
+                let parts = [
+                    rand.next_range(1..self.config.max_part + 1),
+                    rand.next_range(1..self.config.max_part + 1),
+                ];
+                let total = parts.iter().sum();
+                let amount = rand.next_range(self.config.multiples(total)) * total;
+                Box::new(Ratio { parts, amount })
+            }
+        }
+    }
+}
+
+/// Taking a percentage of an amount.
+struct Percentage {
+    percent: u32,
+    amount: u32,
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you work out this percentage for me.")?;
+        write!(f, "What is {}% of {}?", self.percent, self.amount)
+    }
+}
+
+impl Question for Percentage {
+    fn answer(&self, answer: &str) -> Outcome {
+        let expected = self.percent * self.amount / HUNDRED;
+        match u32::from_str(answer.trim()) {
+            Ok(answer) => {
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(_) => Outcome::Invalid(format!(
+                "'{answer}' does not appear to be a valid natural number"
+            )),
+        }
+    }
+}
+
+/// Finding the percentage change between two amounts.
+struct Change {
+    from: u32,
+    to: u32,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you work out the percentage change for me. Use a minus sign for a decrease."
+        )?;
+        write!(
+            f,
+            "What is the percentage change from {} to {}?",
+            self.from, self.to
+        )
+    }
+}
+
+impl Question for Change {
+    fn answer(&self, answer: &str) -> Outcome {
+        let expected =
+            (i64::from(self.to) - i64::from(self.from)) * i64::from(HUNDRED) / i64::from(self.from);
+        match parse_percent(answer) {
+            Ok(answer) => {
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Sharing an amount in a ratio.
+struct Ratio {
+    parts: [u32; 2],
+    amount: u32,
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you share this amount for me. Answer with a ratio; e.g., 10:25."
+        )?;
+        write!(
+            f,
+            "Share {} in the ratio {}:{}.",
+            self.amount, self.parts[0], self.parts[1]
+        )
+    }
+}
+
+impl Question for Ratio {
+    fn answer(&self, answer: &str) -> Outcome {
+        let share = self.amount / self.parts.iter().sum::<u32>();
+        match parse_ratio(answer) {
+            Ok(shares) if shares.len() != self.parts.len() => {
+                Outcome::Invalid(format!("'{answer}' should have {} parts", self.parts.len()))
+            }
+            Ok(shares) => {
+                if shares
+                    .iter()
+                    .zip(self.parts)
+                    .all(|(&actual, part)| actual == part * share)
+                {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Parses a whole percentage, which may be signed and may be followed by a `%`; e.g., `25`,
+/// `+25%` or `-20%`.
+fn parse_percent(answer: &str) -> Result<i64, String> {
+    let trimmed = answer.trim();
+    let trimmed = trimmed.strip_suffix('%').unwrap_or(trimmed).trim_end();
+    let trimmed = trimmed.strip_prefix('+').unwrap_or(trimmed);
+    i64::from_str(trimmed)
+        .map_err(|_| format!("'{answer}' does not appear to be a valid percentage"))
+}
+
+/// Parses a ratio of two or more natural numbers separated by colons; e.g., `10:25` or
+/// `2 : 3 : 5`.
+pub(crate) fn parse_ratio(answer: &str) -> Result<Vec<u32>, String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid ratio; \
+            separate the parts with colons (e.g., '10:25')"
+        )
+    };
+    let parts = answer
+        .split(':')
+        .map(|part| u32::from_str(part.trim()).map_err(|_| err()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if parts.len() < 2 {
+        return Err(err());
+    }
+    Ok(parts)
+}
+
+pub mod presets {
+    use super::{Config, Exercise, Percentages};
+
+    pub fn percentages_1() -> Percentages {
+        Config {
+            min_val: 10,
+            max_val: 200,
+            percent_step: 25,
+            max_part: 5,
+            exercises: vec![Exercise::Percentage, Exercise::Ratio],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn percentages_2() -> Percentages {
+        Config {
+            min_val: 10,
+            max_val: 1_000,
+            percent_step: 5,
+            max_part: 10,
+            exercises: vec![Exercise::Percentage, Exercise::Change, Exercise::Ratio],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
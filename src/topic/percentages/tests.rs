@@ -0,0 +1,221 @@
+use crate::topic::percentages::{parse_ratio, presets, Config, Exercise, Percentages};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+fn config(exercises: Vec<Exercise>) -> Config {
+    Config {
+        min_val: 10,
+        max_val: 1_000,
+        percent_step: 5,
+        max_part: 10,
+        exercises,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::percentages_1();
+    assert_eq!("percentages", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_percentage() {
+    let module = Percentages::try_from(config(vec![Exercise::Percentage])).unwrap();
+
+    let rand_nums = [2, 11];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(20, lim),
+            _ => assert_eq!(49, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you work out this percentage for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("What is 15% of 240?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'36%' does not appear to be a valid natural number".into()),
+        question.answer("36%")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("24"));
+    assert_eq!(Outcome::Correct, question.answer("36"));
+}
+
+#[test]
+fn display_ask_answer_change() {
+    let module =
+        Percentages::try_from(config(vec![Exercise::Percentage, Exercise::Change])).unwrap();
+
+    for (direction, expected_display, expected_answer) in [
+        (0, "What is the percentage change from 80 to 100?", "25%"),
+        (1, "What is the percentage change from 80 to 60?", "-25%"),
+    ] {
+        let rand_nums = [1, 4, 17, direction];
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(
+            s.contains("Can you work out the percentage change for me."),
+            "{}",
+            s
+        );
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(
+            Outcome::Invalid("'a lot' does not appear to be a valid percentage".into()),
+            question.answer("a lot")
+        );
+        assert_eq!(Outcome::Incorrect, question.answer("20%"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+        assert_eq!(
+            Outcome::Correct,
+            question.answer(expected_answer.trim_end_matches('%'))
+        );
+    }
+}
+
+#[test]
+fn display_ask_answer_ratio() {
+    let module = Percentages::try_from(config(vec![Exercise::Ratio])).unwrap();
+
+    let rand_nums = [1, 4, 4];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 | 1 => assert_eq!(10, lim),
+            _ => assert_eq!(141, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you share this amount for me."), "{}", s);
+    assert!(s.contains("Share 42 in the ratio 2:5."), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'12 and 30' does not appear to be a valid ratio; separate the parts with colons (e.g., '10:25')"
+                .into()
+        ),
+        question.answer("12 and 30")
+    );
+    assert_eq!(
+        Outcome::Invalid("'12:30:0' should have 2 parts".into()),
+        question.answer("12:30:0")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("30:12"));
+    assert_eq!(Outcome::Correct, question.answer("12:30"));
+    assert_eq!(Outcome::Correct, question.answer(" 12 : 30 "));
+}
+
+#[test]
+fn parse_ratio_forms() {
+    assert_eq!(Ok(vec![10, 25]), parse_ratio("10:25"));
+    assert_eq!(Ok(vec![2, 3, 5]), parse_ratio("2 : 3 : 5"));
+    assert!(parse_ratio("10").is_err());
+    assert!(parse_ratio("10:").is_err());
+    assert!(parse_ratio("10:-2").is_err());
+    assert!(parse_ratio("1/2").is_err());
+}
+
+#[test]
+fn invalid_config() {
+    let module = Percentages::try_from(Config {
+        min_val: 0,
+        max_val: 1_000,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!("min_val must be greater than zero", module.err().unwrap());
+
+    let module = Percentages::try_from(Config {
+        min_val: 10,
+        max_val: 1_000_001,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!("max_val cannot exceed 1000000", module.err().unwrap());
+
+    let module = Percentages::try_from(Config {
+        min_val: u32::MAX,
+        max_val: 1_000,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Percentages::try_from(Config {
+        min_val: 999_950,
+        max_val: 1_000_000,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!(
+        "max_val must exceed min_val by at least 100",
+        module.err().unwrap()
+    );
+
+    let module = Percentages::try_from(Config {
+        min_val: 10,
+        max_val: 109,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!(
+        "max_val must exceed min_val by at least 100",
+        module.err().unwrap()
+    );
+
+    let module = Percentages::try_from(Config {
+        min_val: 10,
+        max_val: 1_000,
+        percent_step: 30,
+        max_part: 10,
+        exercises: vec![Exercise::Percentage],
+    });
+    assert_eq!(
+        "percent_step must be a divisor of 100",
+        module.err().unwrap()
+    );
+
+    let module = Percentages::try_from(Config {
+        min_val: 10,
+        max_val: 1_000,
+        percent_step: 5,
+        max_part: 1,
+        exercises: vec![Exercise::Ratio],
+    });
+    assert_eq!("max_part must be between 2 and 50", module.err().unwrap());
+
+    let module = Percentages::try_from(Config {
+        min_val: 10,
+        max_val: 1_000,
+        percent_step: 5,
+        max_part: 10,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::percentages_1();
+    presets::percentages_2();
+}
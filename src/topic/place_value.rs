@@ -0,0 +1,287 @@
+//! Questions on place value and number expansion.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The place value module.
+pub struct PlaceValue {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Naming the digit in a given place; e.g., the hundreds digit of 4,372 is `3`.
+    Digit,
+
+    /// Naming the value of the digit in a given place; e.g., the hundreds digit of 4,372 is
+    /// worth `300`.
+    Value,
+
+    /// Writing a number in expanded form; e.g., 5,031 is `5000 + 30 + 1`.
+    Expand,
+}
+
+/// Configuration for [`PlaceValue`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+/// The names of places, starting from the ones.
+const PLACES: [&str; 9] = [
+    "ones",
+    "tens",
+    "hundreds",
+    "thousands",
+    "ten thousands",
+    "hundred thousands",
+    "millions",
+    "ten millions",
+    "hundred millions",
+];
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 1_000_000_000;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for PlaceValue {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for PlaceValue {
+    fn topic_name(&self) -> String {
+        String::from("place value")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let number = rand.next_range(self.config.min_val..self.config.max_val);
+        match exercise {
+            Exercise::Digit | Exercise::Value => {
+                let places = number.max(1).ilog10() + 1;
+                let position = rand.next_range(0..places);
+                Box::new(Place {
+                    number,
+                    position,
+                    value: exercise == Exercise::Value,
+                })
+            }
+            Exercise::Expand => Box::new(Expansion { number }),
+        }
+    }
+}
+
+struct Place {
+    number: u32,
+
+    /// The position of the place being asked about, with `0` being the ones.
+    position: u32,
+
+    /// Whether the value of the digit is asked, rather than the digit itself.
+    value: bool,
+}
+
+impl Place {
+    fn expected(&self) -> u32 {
+        let scale = 10_u32.pow(self.position);
+        let digit = self.number / scale % 10;
+        if self.value {
+            digit * scale
+        } else {
+            digit
+        }
+    }
+}
+
+impl Display for Place {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let number = format_thousands(self.number);
+        let place = PLACES[self.position as usize];
+        if self.value {
+            writeln!(f, "Can you tell me the value of a digit.")?;
+            write!(
+                f,
+                "In {number}, what is the value of the digit in the {place} place?"
+            )
+        } else {
+            writeln!(f, "Can you find a digit for me.")?;
+            write!(f, "What digit is in the {place} place of {number}?")
+        }
+    }
+}
+
+impl Question for Place {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse(answer) {
+            Ok(answer) => {
+                if answer == self.expected() {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+struct Expansion {
+    number: u32,
+}
+
+impl Display for Expansion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Can you expand this number for me, e.g., 5,031 = 5000 + 30 + 1."
+        )?;
+        write!(
+            f,
+            "Write {} in expanded form.",
+            format_thousands(self.number)
+        )
+    }
+}
+
+impl Question for Expansion {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_expansion(answer) {
+            Ok(terms) => {
+                let terms = terms
+                    .into_iter()
+                    .filter(|&term| term != 0)
+                    .collect::<Vec<_>>();
+                let places = terms
+                    .iter()
+                    .map(|&term| place_of(term))
+                    .collect::<Option<Vec<_>>>();
+                let expanded = places.is_some_and(|mut places| {
+                    places.sort_unstable();
+                    places.dedup();
+                    places.len() == terms.len()
+                });
+                let sum = terms.iter().map(|&term| u64::from(term)).sum::<u64>();
+                if expanded && sum == u64::from(self.number) {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Obtains the place of a term in an expanded number, if the term is a single non-zero digit
+/// followed by zeros.
+fn place_of(term: u32) -> Option<u32> {
+    let place = term.ilog10();
+    if term.is_multiple_of(10_u32.pow(place)) {
+        Some(place)
+    } else {
+        None
+    }
+}
+
+/// Formats a number with commas separating the thousands; e.g., `4,372`.
+pub(crate) fn format_thousands(number: u32) -> String {
+    let digits = number.to_string();
+    let mut formatted = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Parses a natural number, which may have its thousands separated by commas or spaces.
+pub(crate) fn parse(answer: &str) -> Result<u32, String> {
+    let stripped = answer
+        .trim()
+        .chars()
+        .filter(|&ch| ch != ',' && ch != ' ')
+        .collect::<String>();
+    u32::from_str(&stripped)
+        .map_err(|_| format!("'{answer}' does not appear to be a valid natural number"))
+}
+
+/// Parses a number in expanded form, comprising terms separated by `+`. Each term may be written
+/// as a number (`300`, `5,000`) or as a digit multiplied by its place (`3 × 100`, `3 x 100`,
+/// `3 * 100`). The terms may appear in any order.
+fn parse_expansion(answer: &str) -> Result<Vec<u32>, String> {
+    answer
+        .split('+')
+        .map(|term| {
+            let factors = term
+                .split(['×', 'x', 'X', '*'])
+                .map(parse)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| {
+                    format!(
+                        "'{answer}' does not appear to be in expanded form; \
+                        write the terms separated by '+' (e.g., '5000 + 30 + 1' or '5 × 1000 + 3 × 10 + 1')"
+                    )
+                })?;
+            Ok(factors.into_iter().fold(1_u32, u32::saturating_mul))
+        })
+        .collect()
+}
+
+pub mod presets {
+    use super::{Config, Exercise, PlaceValue};
+
+    pub fn place_value_1() -> PlaceValue {
+        Config {
+            min_val: 10,
+            max_val: 1_000,
+            exercises: vec![Exercise::Digit, Exercise::Value],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn place_value_2() -> PlaceValue {
+        Config {
+            min_val: 1_000,
+            max_val: 1_000_000,
+            exercises: vec![Exercise::Digit, Exercise::Value, Exercise::Expand],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+use crate::topic::place_value::{
+    format_thousands, parse_expansion, presets, Config, Exercise, PlaceValue,
+};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+fn module() -> PlaceValue {
+    PlaceValue::try_from(Config {
+        min_val: 1_000,
+        max_val: 10_000,
+        exercises: vec![Exercise::Digit, Exercise::Value, Exercise::Expand],
+    })
+    .unwrap()
+}
+
+#[test]
+fn name() {
+    let module = presets::place_value_1();
+    assert_eq!("place value", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_digit() {
+    let rand_nums = [0, 3_372, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(3, lim),
+            1 => assert_eq!(9_000, lim),
+            _ => assert_eq!(4, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module().ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you find a digit for me."), "{}", s);
+    assert!(
+        s.contains("What digit is in the hundreds place of 4,372?"),
+        "{}",
+        s
+    );
+
+    assert_eq!(
+        Outcome::Invalid("'three' does not appear to be a valid natural number".into()),
+        question.answer("three")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("300"));
+    assert_eq!(Outcome::Incorrect, question.answer("7"));
+    assert_eq!(Outcome::Correct, question.answer("3"));
+}
+
+#[test]
+fn display_ask_answer_value() {
+    let rand_nums = [1, 3_372, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module().ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("In 4,372, what is the value of the digit in the thousands place?"),
+        "{}",
+        s
+    );
+
+    assert_eq!(Outcome::Incorrect, question.answer("4"));
+    assert_eq!(Outcome::Correct, question.answer("4000"));
+    assert_eq!(Outcome::Correct, question.answer("4,000"));
+}
+
+#[test]
+fn display_ask_answer_expand() {
+    let rand_nums = [2, 4_031];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module().ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Write 5,031 in expanded form."), "{}", s);
+    assert_eq!(2, rand.state().next_lim_u128_invocations());
+
+    assert!(matches!(question.answer("5000 - 31"), Outcome::Invalid(_)));
+    assert_eq!(Outcome::Incorrect, question.answer("5031"));
+    assert_eq!(Outcome::Incorrect, question.answer("5000 + 31"));
+    assert_eq!(Outcome::Incorrect, question.answer("5000 + 20 + 10 + 1"));
+    assert_eq!(Outcome::Incorrect, question.answer("5000 + 30 + 2"));
+    assert_eq!(Outcome::Correct, question.answer("5000 + 30 + 1"));
+    assert_eq!(Outcome::Correct, question.answer("5,000+30+1"));
+    assert_eq!(Outcome::Correct, question.answer("1 + 30 + 5000"));
+    assert_eq!(Outcome::Correct, question.answer("5000 + 0 + 30 + 1"));
+    assert_eq!(Outcome::Correct, question.answer("5 × 1000 + 3 × 10 + 1"));
+    assert_eq!(
+        Outcome::Correct,
+        question.answer("5 x 1000 + 3 * 10 + 1 x 1")
+    );
+}
+
+#[test]
+fn expansion_terms() {
+    assert_eq!(Ok(vec![5000, 30, 1]), parse_expansion("5000 + 30 + 1"));
+    assert_eq!(Ok(vec![5000, 30]), parse_expansion("5 × 1,000 + 3x10"));
+    assert!(parse_expansion("").is_err());
+    assert!(parse_expansion("5000 +").is_err());
+    assert!(parse_expansion("five thousand").is_err());
+}
+
+#[test]
+fn thousands() {
+    assert_eq!("0", format_thousands(0));
+    assert_eq!("999", format_thousands(999));
+    assert_eq!("4,372", format_thousands(4_372));
+    assert_eq!("123,456,789", format_thousands(123_456_789));
+}
+
+#[test]
+fn invalid_config() {
+    let module = PlaceValue::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        exercises: vec![Exercise::Digit],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = PlaceValue::try_from(Config {
+        min_val: 10,
+        max_val: 1_000_000_001,
+        exercises: vec![Exercise::Digit],
+    });
+    assert_eq!("max_val cannot exceed 1000000000", module.err().unwrap());
+
+    let module = PlaceValue::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::place_value_1();
+    presets::place_value_2();
+}
@@ -0,0 +1,296 @@
+//! Questions on squares, cubes and other powers, and their roots.
+
+use crate::topic::{choose, decimals, place_value, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The powers module.
+pub struct Powers {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Raising a base to a power; e.g., `7² = ?`.
+    Power,
+
+    /// Taking the root of a number; e.g., `∛64 = ?`.
+    Root,
+}
+
+/// How powers and roots are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// Superscript exponents and radical signs; e.g., `7²` and `∛64`.
+    Unicode,
+
+    /// A fallback for terminals that cannot render the above; e.g., `7^2` and `64^(1/3)`.
+    Ascii,
+}
+
+/// Configuration for [`Powers`].
+pub struct Config {
+    /// The smallest base (or root) that will be asked.
+    pub min_base: u32,
+
+    /// The largest base (or root) that will be asked.
+    pub max_base: u32,
+
+    /// The exponents to choose from; `2` for squares and square roots, `3` for cubes and cube
+    /// roots, and so on.
+    pub exponents: Vec<u32>,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+
+    /// Only ask for the roots of perfect powers. Otherwise, roots are given to one decimal place.
+    pub perfect_roots: bool,
+
+    /// How powers and roots are written.
+    pub notation: Notation,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_EXPONENT: u32 = 10;
+        const MAX_POWER: u64 = 1_000_000_000;
+        if self.min_base >= self.max_base {
+            return Err("min_base must be less than max_base".into());
+        }
+        if self.exponents.is_empty() {
+            return Err("at least one exponent must be specified".into());
+        }
+        if self
+            .exponents
+            .iter()
+            .any(|exponent| !(2..=MAX_EXPONENT).contains(exponent))
+        {
+            return Err(format!("exponents must be between 2 and {MAX_EXPONENT}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        let max_exponent = self.exponents.iter().max().unwrap();
+        if u64::from(self.max_base - 1)
+            .checked_pow(*max_exponent)
+            .is_none_or(|power| power > MAX_POWER)
+        {
+            return Err(format!("the largest power cannot exceed {MAX_POWER}"));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Powers {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Powers {
+    fn topic_name(&self) -> String {
+        String::from("powers")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let exponent = *choose(&self.config.exponents, rand);
+        let notation = self.config.notation;
+        match exercise {
+            Exercise::Power => {
+                let base = rand.next_range(self.config.min_base..self.config.max_base);
+                Box::new(Power {
+                    base,
+                    exponent,
+                    notation,
+                })
+            }
+            Exercise::Root if self.config.perfect_roots => {
+                let root = rand.next_range(self.config.min_base..self.config.max_base);
+                Box::new(Root {
+                    radicand: root.pow(exponent),
+                    exponent,
+                    perfect: true,
+                    notation,
+                })
+            }
+            Exercise::Root => {
+                let min_radicand = self.config.min_base.pow(exponent);
+                let max_radicand = (self.config.max_base - 1).pow(exponent);
+                Box::new(Root {
+                    radicand: rand.next_range(min_radicand..max_radicand + 1),
+                    exponent,
+                    perfect: false,
+                    notation,
+                })
+            }
+        }
+    }
+}
+
+/// Writes a number in superscript; e.g., `10` as `¹⁰`.
+fn superscript(number: u32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    number
+        .to_string()
+        .chars()
+        .map(|digit| DIGITS[digit.to_digit(10).unwrap() as usize])
+        .collect()
+}
+
+/// Raising a base to a power.
+struct Power {
+    base: u32,
+    exponent: u32,
+    notation: Notation,
+}
+
+impl Display for Power {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you work out this power for me.")?;
+        match self.notation {
+            Notation::Unicode => write!(f, "{}{} = ?", self.base, superscript(self.exponent)),
+            Notation::Ascii => write!(f, "{}^{} = ?", self.base, self.exponent),
+        }
+    }
+}
+
+impl Question for Power {
+    fn answer(&self, answer: &str) -> Outcome {
+        match place_value::parse(answer) {
+            Ok(answer) => {
+                if answer == self.base.pow(self.exponent) {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Taking the root of a number.
+struct Root {
+    radicand: u32,
+    exponent: u32,
+
+    /// Whether the radicand is known to be a perfect power, in which case the root must be given
+    /// as a natural number.
+    perfect: bool,
+    notation: Notation,
+}
+
+impl Root {
+    /// The root, rounded to the nearest tenth and scaled by 10.
+    fn scaled_root(&self) -> u64 {
+        let radicand = u128::from(self.radicand);
+        let exponent = self.exponent;
+
+        // find the largest number of tenths whose power does not exceed the radicand, then round
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mut tenths = (f64::from(self.radicand).powf(1.0 / f64::from(exponent)) * 10.0) as u128;
+        while tenths > 0 && tenths.pow(exponent) > radicand * 10_u128.pow(exponent) {
+            tenths -= 1;
+        }
+        while (tenths + 1).pow(exponent) <= radicand * 10_u128.pow(exponent) {
+            tenths += 1;
+        }
+        if (2 * tenths + 1).pow(exponent) <= radicand * 20_u128.pow(exponent) {
+            tenths += 1;
+        }
+        u64::try_from(tenths).unwrap()
+    }
+}
+
+impl Display for Root {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.perfect {
+            writeln!(f, "Can you work out this root for me.")?;
+        } else {
+            writeln!(
+                f,
+                "Can you work out this root for me. Give your answer to 1 decimal place."
+            )?;
+        }
+        match (self.notation, self.exponent) {
+            (Notation::Unicode, 2) => write!(f, "√{} = ?", self.radicand),
+            (Notation::Unicode, 3) => write!(f, "∛{} = ?", self.radicand),
+            (Notation::Unicode, 4) => write!(f, "∜{} = ?", self.radicand),
+            (Notation::Unicode, _) => {
+                write!(f, "{}√{} = ?", superscript(self.exponent), self.radicand)
+            }
+            (Notation::Ascii, 2) => write!(f, "sqrt({}) = ?", self.radicand),
+            (Notation::Ascii, _) => write!(f, "{}^(1/{}) = ?", self.radicand, self.exponent),
+        }
+    }
+}
+
+impl Question for Root {
+    fn answer(&self, answer: &str) -> Outcome {
+        if self.perfect {
+            match place_value::parse(answer) {
+                Ok(answer) => {
+                    if u64::from(answer) * 10 == self.scaled_root() {
+                        Outcome::Correct
+                    } else {
+                        Outcome::Incorrect
+                    }
+                }
+                Err(err) => Outcome::Invalid(err),
+            }
+        } else {
+            match decimals::parse_scaled(answer, 1) {
+                Ok(answer) => {
+                    if answer == self.scaled_root() {
+                        Outcome::Correct
+                    } else {
+                        Outcome::Incorrect
+                    }
+                }
+                Err(err) => Outcome::Invalid(err),
+            }
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Exercise, Notation, Powers};
+
+    pub fn powers_1() -> Powers {
+        Config {
+            min_base: 1,
+            max_base: 13,
+            exponents: vec![2],
+            exercises: vec![Exercise::Power, Exercise::Root],
+            perfect_roots: true,
+            notation: Notation::Unicode,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn powers_2() -> Powers {
+        Config {
+            min_base: 1,
+            max_base: 11,
+            exponents: vec![2, 3],
+            exercises: vec![Exercise::Power, Exercise::Root],
+            perfect_roots: true,
+            notation: Notation::Unicode,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,292 @@
+use crate::topic::powers::{presets, Config, Exercise, Notation, Powers};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+fn config(exercises: Vec<Exercise>, perfect_roots: bool, notation: Notation) -> Config {
+    Config {
+        min_base: 1,
+        max_base: 8,
+        exponents: vec![2, 3, 10],
+        exercises,
+        perfect_roots,
+        notation,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::powers_1();
+    assert_eq!("powers", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_power() {
+    for (notation, rand_nums, expected_display, expected_answer) in [
+        (Notation::Unicode, [0, 6], "7² = ?", "49"),
+        (Notation::Ascii, [0, 6], "7^2 = ?", "49"),
+        (Notation::Unicode, [2, 1], "2¹⁰ = ?", "1,024"),
+        (Notation::Ascii, [2, 1], "2^10 = ?", "1024"),
+    ] {
+        let module = Powers::try_from(config(vec![Exercise::Power], true, notation)).unwrap();
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            match surrogate.state().next_lim_u128_invocations() {
+                0 => assert_eq!(3, lim),
+                _ => assert_eq!(7, lim),
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains("Can you work out this power for me."), "{}", s);
+        assert!(s.contains(expected_display), "{}", s);
+
+        assert_eq!(
+            Outcome::Invalid("'x' does not appear to be a valid natural number".into()),
+            question.answer("x")
+        );
+        assert_eq!(Outcome::Incorrect, question.answer("14"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_perfect_root() {
+    for (notation, rand_nums, expected_display, expected_answer) in [
+        (Notation::Unicode, [1, 1, 3], "∛64 = ?", "4"),
+        (Notation::Ascii, [1, 1, 3], "64^(1/3) = ?", "4"),
+        (Notation::Unicode, [1, 0, 6], "√49 = ?", "7"),
+        (Notation::Ascii, [1, 0, 6], "sqrt(49) = ?", "7"),
+        (Notation::Unicode, [1, 2, 1], "¹⁰√1024 = ?", "2"),
+    ] {
+        let module = Powers::try_from(config(
+            vec![Exercise::Power, Exercise::Root],
+            true,
+            notation,
+        ))
+        .unwrap();
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            match surrogate.state().next_lim_u128_invocations() {
+                0 => assert_eq!(2, lim),
+                1 => assert_eq!(3, lim),
+                _ => assert_eq!(7, lim),
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains("Can you work out this root for me."), "{}", s);
+        assert!(!s.contains("decimal place"), "{}", s);
+        assert!(s.contains(expected_display), "{}", s);
+
+        assert_eq!(
+            Outcome::Invalid("'4.0' does not appear to be a valid natural number".into()),
+            question.answer("4.0")
+        );
+        assert_eq!(Outcome::Incorrect, question.answer("5"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_imperfect_root() {
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![2],
+        exercises: vec![Exercise::Root],
+        perfect_roots: false,
+        notation: Notation::Unicode,
+    })
+    .unwrap();
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(100, lim);
+        49
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you work out this root for me. Give your answer to 1 decimal place."),
+        "{}",
+        s
+    );
+    assert!(s.contains("√50 = ?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'seven' does not appear to be a valid decimal number".into()),
+        question.answer("seven")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("7"));
+    assert_eq!(
+        Outcome::Invalid("'7.07' has more than 1 decimal place".into()),
+        question.answer("7.07")
+    );
+    assert_eq!(Outcome::Correct, question.answer("7.1"));
+    assert_eq!(Outcome::Correct, question.answer("7.10"));
+    assert_eq!(Outcome::Correct, question.answer("7,1"));
+}
+
+#[test]
+fn imperfect_roots_are_rounded() {
+    let module = Powers::try_from(Config {
+        min_base: 0,
+        max_base: 101,
+        exponents: vec![2, 3, 4],
+        exercises: vec![Exercise::Root],
+        perfect_roots: false,
+        notation: Notation::Ascii,
+    })
+    .unwrap();
+    let mut rand = StdRand::default();
+    for _ in 0..1_000 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let body = s.lines().nth(1).unwrap();
+        let (radicand, exponent) = match body.strip_prefix("sqrt(") {
+            Some(rest) => (rest.split(')').next().unwrap(), 2),
+            None => {
+                let (radicand, rest) = body.split_once("^(1/").unwrap();
+                (radicand, rest.split(')').next().unwrap().parse().unwrap())
+            }
+        };
+        let root = radicand
+            .parse::<f64>()
+            .unwrap()
+            .powf(1.0 / f64::from(exponent));
+        let expected = format!("{root:.1}");
+        assert_eq!(Outcome::Correct, question.answer(&expected), "{s}");
+    }
+}
+
+#[test]
+fn ask_within_range() {
+    let module = presets::powers_2();
+    let mut rand = StdRand::default();
+    let root = |radicand: &str, exponent: u32| {
+        let radicand = radicand.parse::<u32>().unwrap();
+        let root = (1..=radicand)
+            .find(|root: &u32| root.pow(exponent) >= radicand)
+            .unwrap();
+        assert_eq!(
+            radicand,
+            root.pow(exponent),
+            "{radicand} is not a perfect power"
+        );
+        root
+    };
+    for _ in 0..100 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let expr = s.lines().nth(1).unwrap().strip_suffix(" = ?").unwrap();
+        let (base, expected) = if let Some(radicand) = expr.strip_prefix('√') {
+            let base = root(radicand, 2);
+            (base, base)
+        } else if let Some(radicand) = expr.strip_prefix('∛') {
+            let base = root(radicand, 3);
+            (base, base)
+        } else if let Some(base) = expr.strip_suffix('²') {
+            let base = base.parse::<u32>().unwrap();
+            (base, base.pow(2))
+        } else {
+            let base = expr.strip_suffix('³').unwrap().parse::<u32>().unwrap();
+            (base, base.pow(3))
+        };
+        assert!((1..11).contains(&base), "{s}");
+        assert_eq!(
+            Outcome::Correct,
+            question.answer(&expected.to_string()),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Powers::try_from(Config {
+        min_base: 11,
+        max_base: 11,
+        exponents: vec![2],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!("min_base must be less than max_base", module.err().unwrap());
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!(
+        "at least one exponent must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![1],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!("exponents must be between 2 and 10", module.err().unwrap());
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![2, 11],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!("exponents must be between 2 and 10", module.err().unwrap());
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![2],
+        exercises: vec![],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 11,
+        exponents: vec![2, 10],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert_eq!(
+        "the largest power cannot exceed 1000000000",
+        module.err().unwrap()
+    );
+
+    let module = Powers::try_from(Config {
+        min_base: 1,
+        max_base: 8,
+        exponents: vec![2, 10],
+        exercises: vec![Exercise::Power],
+        perfect_roots: true,
+        notation: Notation::Unicode,
+    });
+    assert!(module.is_ok());
+}
+
+#[test]
+fn presets() {
+    presets::powers_1();
+    presets::powers_2();
+}
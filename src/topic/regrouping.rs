@@ -0,0 +1,276 @@
+//! Control over regrouping; i.e., carrying in addition and borrowing in subtraction.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use tinyrand::RandRange;
+
+/// A constraint on the number of regroupings (carries in addition, borrows in subtraction) that
+/// a question requires when worked in columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regrouping {
+    /// No constraint.
+    Any,
+
+    /// The question must not require regrouping.
+    Forbid,
+
+    /// The question must require at least one regrouping.
+    Require,
+
+    /// The question must require exactly the given number of regroupings.
+    Exactly(u32),
+}
+
+impl Regrouping {
+    fn admits(self, regroupings: u32) -> bool {
+        match self {
+            Regrouping::Any => true,
+            Regrouping::Forbid => regroupings == 0,
+            Regrouping::Require => regroupings > 0,
+            Regrouping::Exactly(expected) => regroupings == expected,
+        }
+    }
+}
+
+/// The column operation whose regroupings are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Add,
+    Subtract,
+}
+
+/// The ordering imposed on a sampled pair `(x, y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Order {
+    Unordered,
+    GreaterOrEqual,
+    Greater,
+}
+
+/// The state of a partially generated pair, working from the most significant column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    /// The column about to be generated, with `0` being the most significant.
+    column: u32,
+
+    /// Whether this column must produce a carry (or a borrow) into the column to its left.
+    regroup: bool,
+
+    /// The number of regroupings produced by the columns generated so far, including this
+    /// column's.
+    regroupings: u32,
+
+    /// Whether the digits of `x` generated so far coincide with those of its lower bound.
+    x_low: bool,
+
+    /// Whether the digits of `x` generated so far coincide with those of its upper bound.
+    x_high: bool,
+
+    /// Whether the digits of `y` generated so far coincide with those of its lower bound.
+    y_low: bool,
+
+    /// Whether the digits of `y` generated so far coincide with those of its upper bound.
+    y_high: bool,
+
+    /// Whether `x` is known to be strictly greater than `y`.
+    greater: bool,
+}
+
+/// Samples pairs of operands `(x, y)` from the given ranges, such that the number of regroupings
+/// in `x + y` (or `x – y`) satisfies a [`Regrouping`] constraint.
+///
+/// The pairs are built digit by digit, guided by the number of admissible completions of each
+/// partial pair. These counts are computed once, upfront, so that sampling never has to reject a
+/// generated pair.
+pub(crate) struct PairSampler {
+    operation: Operation,
+    order: Order,
+    x: RangeInclusive<u32>,
+    y: RangeInclusive<u32>,
+    regrouping: Regrouping,
+    columns: u32,
+    roots: Vec<State>,
+    counts: HashMap<State, u128>,
+}
+
+impl PairSampler {
+    pub(crate) fn new(
+        operation: Operation,
+        order: Order,
+        x: RangeInclusive<u32>,
+        y: RangeInclusive<u32>,
+        regrouping: Regrouping,
+    ) -> Self {
+        let columns = (*x.end()).max(*y.end()).max(1).ilog10() + 1;
+        let carries_out = match operation {
+            Operation::Add => vec![false, true],
+            Operation::Subtract => vec![false],
+        };
+        let roots = carries_out
+            .into_iter()
+            .map(|regroup| State {
+                column: 0,
+                regroup,
+                regroupings: u32::from(regroup),
+                x_low: true,
+                x_high: true,
+                y_low: true,
+                y_high: true,
+                greater: false,
+            })
+            .collect();
+        let mut sampler = Self {
+            operation,
+            order,
+            x,
+            y,
+            regrouping,
+            columns,
+            roots,
+            counts: HashMap::new(),
+        };
+        let mut counts = HashMap::new();
+        for root in &sampler.roots {
+            sampler.count(*root, &mut counts);
+        }
+        sampler.counts = counts;
+        sampler
+    }
+
+    /// The number of distinct pairs that may be sampled.
+    pub(crate) fn total(&self) -> u128 {
+        self.roots.iter().map(|root| self.counts[root]).sum()
+    }
+
+    /// Samples a pair. The sampler must admit at least one pair.
+    pub(crate) fn sample(&self, rand: &mut dyn RandRange<u32>) -> (u32, u32) {
+        let weights = self
+            .roots
+            .iter()
+            .map(|root| self.counts[root])
+            .collect::<Vec<_>>();
+        let mut state = self.roots[pick(&weights, rand)];
+        let (mut x, mut y) = (0, 0);
+        while state.column < self.columns {
+            let transitions = self.transitions(state);
+            let weights = transitions
+                .iter()
+                .map(|(_, _, next)| self.counts[next])
+                .collect::<Vec<_>>();
+            let (x_digit, y_digit, next) = transitions[pick(&weights, rand)];
+            x = x * 10 + x_digit;
+            y = y * 10 + y_digit;
+            state = next;
+        }
+        (x, y)
+    }
+
+    fn count(&self, state: State, counts: &mut HashMap<State, u128>) -> u128 {
+        if let Some(&count) = counts.get(&state) {
+            return count;
+        }
+        let count = if state.column == self.columns {
+            let ordered = self.order != Order::Greater || state.greater;
+            u128::from(!state.regroup && ordered && self.regrouping.admits(state.regroupings))
+        } else {
+            self.transitions(state)
+                .into_iter()
+                .map(|(_, _, next)| self.count(next, counts))
+                .sum()
+        };
+        counts.insert(state, count);
+        count
+    }
+
+    /// Enumerates the digits that may be placed in the current column, alongside the resulting
+    /// state.
+    fn transitions(&self, state: State) -> Vec<(u32, u32, State)> {
+        let x_digits = digit_range(
+            &self.x,
+            state.x_low,
+            state.x_high,
+            state.column,
+            self.columns,
+        );
+        let y_digits = digit_range(
+            &self.y,
+            state.y_low,
+            state.y_high,
+            state.column,
+            self.columns,
+        );
+        let regroups_in: &[bool] = if state.column + 1 == self.columns {
+            &[false]
+        } else {
+            &[false, true]
+        };
+
+        let mut transitions = vec![];
+        for x_digit in x_digits.clone() {
+            for y_digit in y_digits.clone() {
+                if self.order != Order::Unordered && !state.greater && x_digit < y_digit {
+                    continue;
+                }
+                for &regroup_in in regroups_in {
+                    let carry_in = u32::from(regroup_in);
+                    let regroup_out = match self.operation {
+                        Operation::Add => x_digit + y_digit + carry_in >= 10,
+                        Operation::Subtract => x_digit < y_digit + carry_in,
+                    };
+                    if regroup_out != state.regroup {
+                        continue;
+                    }
+                    let next = State {
+                        column: state.column + 1,
+                        regroup: regroup_in,
+                        regroupings: state.regroupings + carry_in,
+                        x_low: state.x_low && x_digit == *x_digits.start(),
+                        x_high: state.x_high && x_digit == *x_digits.end(),
+                        y_low: state.y_low && y_digit == *y_digits.start(),
+                        y_high: state.y_high && y_digit == *y_digits.end(),
+                        greater: state.greater || x_digit > y_digit,
+                    };
+                    transitions.push((x_digit, y_digit, next));
+                }
+            }
+        }
+        transitions
+    }
+}
+
+/// The range of digits that may appear in the given column of a number in `range`, given whether
+/// the more significant digits coincide with those of the range's bounds.
+fn digit_range(
+    range: &RangeInclusive<u32>,
+    low: bool,
+    high: bool,
+    column: u32,
+    columns: u32,
+) -> RangeInclusive<u32> {
+    let place = 10_u32.pow(columns - column - 1);
+    let start = if low { range.start() / place % 10 } else { 0 };
+    let end = if high { range.end() / place % 10 } else { 9 };
+    start..=end
+}
+
+/// Picks an index at random, with each index weighted by its corresponding value in `weights`.
+/// Weights that do not fit into a `u32` are scaled down, which introduces a slight bias.
+pub(crate) fn pick(weights: &[u128], rand: &mut dyn RandRange<u32>) -> usize {
+    let total = weights.iter().sum::<u128>();
+    assert_ne!(0, total, "no weighted choices");
+    let target = match u32::try_from(total) {
+        Ok(total) => u128::from(rand.next_range(0..total)),
+        Err(_) => u128::from(rand.next_range(0..u32::MAX)) * total / u128::from(u32::MAX),
+    };
+    let mut cumulative = 0;
+    weights
+        .iter()
+        .position(|&weight| {
+            cumulative += weight;
+            target < cumulative
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+use crate::topic::regrouping::{pick, Operation, Order, PairSampler, Regrouping};
+use std::ops::RangeInclusive;
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+/// Counts the regroupings in `x + y` or `x – y` the slow way.
+fn regroupings(operation: Operation, mut x: u32, mut y: u32) -> u32 {
+    let mut regroupings = 0;
+    let mut carry = 0;
+    while x > 0 || y > 0 {
+        let regroup = match operation {
+            Operation::Add => x % 10 + y % 10 + carry >= 10,
+            Operation::Subtract => x % 10 < y % 10 + carry,
+        };
+        carry = u32::from(regroup);
+        regroupings += carry;
+        x /= 10;
+        y /= 10;
+    }
+    regroupings
+}
+
+fn admissible(
+    operation: Operation,
+    order: Order,
+    x: &RangeInclusive<u32>,
+    y: &RangeInclusive<u32>,
+    regrouping: Regrouping,
+) -> Vec<(u32, u32)> {
+    x.clone()
+        .flat_map(|x| y.clone().map(move |y| (x, y)))
+        .filter(|&(x, y)| match order {
+            Order::Unordered => true,
+            Order::GreaterOrEqual => x >= y,
+            Order::Greater => x > y,
+        })
+        .filter(|&(x, y)| match regrouping {
+            Regrouping::Any => true,
+            Regrouping::Forbid => regroupings(operation, x, y) == 0,
+            Regrouping::Require => regroupings(operation, x, y) > 0,
+            Regrouping::Exactly(expected) => regroupings(operation, x, y) == expected,
+        })
+        .collect()
+}
+
+#[test]
+fn sampler_agrees_with_brute_force() {
+    let mut rand = StdRand::default();
+    for (operation, order) in [
+        (Operation::Add, Order::Unordered),
+        (Operation::Subtract, Order::GreaterOrEqual),
+        (Operation::Subtract, Order::Greater),
+    ] {
+        for (x, y) in [(0..=9, 0..=9), (7..=123, 0..=123), (95..=1_050, 3..=87)] {
+            for regrouping in [
+                Regrouping::Any,
+                Regrouping::Forbid,
+                Regrouping::Require,
+                Regrouping::Exactly(1),
+                Regrouping::Exactly(2),
+                Regrouping::Exactly(3),
+            ] {
+                let expected = admissible(operation, order, &x, &y, regrouping);
+                let sampler = PairSampler::new(operation, order, x.clone(), y.clone(), regrouping);
+                assert_eq!(
+                    expected.len() as u128,
+                    sampler.total(),
+                    "{operation:?}, {order:?}, {x:?}, {y:?}, {regrouping:?}"
+                );
+                if expected.is_empty() {
+                    continue;
+                }
+                for _ in 0..100 {
+                    let pair = sampler.sample(&mut rand);
+                    assert!(
+                        expected.contains(&pair),
+                        "{pair:?} for {operation:?}, {order:?}, {x:?}, {y:?}, {regrouping:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn sampler_covers_large_ranges() {
+    let mut rand = StdRand::default();
+    let sampler = PairSampler::new(
+        Operation::Add,
+        Order::Unordered,
+        0..=u32::MAX >> 1,
+        0..=u32::MAX >> 1,
+        Regrouping::Exactly(4),
+    );
+    for _ in 0..100 {
+        let (x, y) = sampler.sample(&mut rand);
+        assert!(x <= u32::MAX >> 1);
+        assert!(y <= u32::MAX >> 1);
+        assert_eq!(4, regroupings(Operation::Add, x, y), "{x} + {y}");
+    }
+}
+
+#[test]
+fn pick_weighted() {
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(6, lim);
+        3
+    });
+    assert_eq!(2, pick(&[1, 2, 3], &mut rand));
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(u128::from(u32::MAX), lim);
+        lim - 1
+    });
+    assert_eq!(1, pick(&[1, u128::from(u32::MAX) * 2], &mut rand));
+}
@@ -0,0 +1,272 @@
+//! Questions on converting between numbers and Roman numerals.
+
+use crate::topic::{choose, place_value, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The Roman numerals module.
+pub struct RomanNumerals {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Writing a number in Roman numerals; e.g., `14` as `XIV`.
+    ToRoman,
+
+    /// Writing a Roman numeral as a number; e.g., `XIV` as `14`.
+    FromRoman,
+}
+
+/// Configuration for [`RomanNumerals`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+/// The largest number that can be written in (standard) Roman numerals.
+const MAX_ROMAN: u32 = 3_999;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = MAX_ROMAN + 1;
+        if self.min_val == 0 {
+            return Err("min_val must be at least 1".into());
+        }
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for RomanNumerals {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for RomanNumerals {
+    fn topic_name(&self) -> String {
+        String::from("roman numerals")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let exercise = *choose(&self.config.exercises, rand);
+        let number = rand.next_range(self.config.min_val..self.config.max_val);
+        match exercise {
+            Exercise::ToRoman => Box::new(ToRoman { number }),
+            Exercise::FromRoman => Box::new(FromRoman { number }),
+        }
+    }
+}
+
+/// The letters of Roman numerals and their values.
+const LETTERS: [(char, u32); 7] = [
+    ('I', 1),
+    ('V', 5),
+    ('X', 10),
+    ('L', 50),
+    ('C', 100),
+    ('D', 500),
+    ('M', 1_000),
+];
+
+/// The symbols used to write numbers in their standard form, from largest to smallest.
+const SYMBOLS: [(&str, u32); 13] = [
+    ("M", 1_000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// Writes a number between 1 and 3999 in Roman numerals, in its standard form.
+pub(crate) fn to_roman(mut number: u32) -> String {
+    let mut numeral = String::new();
+    for (symbol, value) in SYMBOLS {
+        while number >= value {
+            numeral.push_str(symbol);
+            number -= value;
+        }
+    }
+    numeral
+}
+
+fn letter_value(letter: char) -> Option<u32> {
+    LETTERS
+        .iter()
+        .find(|&&(candidate, _)| candidate == letter)
+        .map(|&(_, value)| value)
+}
+
+/// Parses a Roman numeral, which may be written in either case. Only numerals in their standard
+/// form are accepted; a malformed numeral, such as `IIII` or `VX`, is rejected with an
+/// explanation of what is wrong with it.
+pub(crate) fn parse_roman(answer: &str) -> Result<u32, String> {
+    let numeral = answer.trim().to_ascii_uppercase();
+    let values = numeral
+        .chars()
+        .map(letter_value)
+        .collect::<Option<Vec<_>>>()
+        .filter(|values| !values.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "'{answer}' does not appear to be a Roman numeral; \
+                write it using the letters I, V, X, L, C, D and M"
+            )
+        })?;
+    let invalid = |reason: String| format!("'{answer}' is not a valid Roman numeral; {reason}");
+
+    let letters = numeral.chars().collect::<Vec<_>>();
+    for run in letters.chunk_by(|a, b| a == b) {
+        let letter = run[0];
+        if run.len() > 1 && matches!(letter, 'V' | 'L' | 'D') {
+            return Err(invalid(format!("{letter} is never repeated")));
+        }
+        if run.len() > 3 {
+            return Err(invalid(format!(
+                "{letter} cannot appear more than three times in a row"
+            )));
+        }
+    }
+
+    let mut value = 0;
+    for (index, &current) in values.iter().enumerate() {
+        match values.get(index + 1) {
+            Some(&next) if current < next => {
+                if !matches!(current, 1 | 10 | 100) || next > current * 10 {
+                    return Err(invalid(format!(
+                        "{} cannot be placed before {} to subtract",
+                        letters[index],
+                        letters[index + 1]
+                    )));
+                }
+                value -= i64::from(current);
+            }
+            _ => value += i64::from(current),
+        }
+    }
+
+    // every subtracted letter is outweighed by the larger one that follows it
+    let value = u32::try_from(value).unwrap();
+    if value > MAX_ROMAN {
+        return Err(invalid(format!(
+            "the largest Roman numeral is {} ({MAX_ROMAN})",
+            to_roman(MAX_ROMAN)
+        )));
+    }
+    if numeral != to_roman(value) {
+        return Err(invalid("its letters are not in the standard order".into()));
+    }
+    Ok(value)
+}
+
+/// Writing a number in Roman numerals.
+struct ToRoman {
+    number: u32,
+}
+
+impl Display for ToRoman {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you write this number in Roman numerals for me.")?;
+        write!(f, "{}", self.number)
+    }
+}
+
+impl Question for ToRoman {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_roman(answer) {
+            Ok(answer) => {
+                if answer == self.number {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Writing a Roman numeral as a number.
+struct FromRoman {
+    number: u32,
+}
+
+impl Display for FromRoman {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you write this Roman numeral as a number for me.")?;
+        write!(f, "{}", to_roman(self.number))
+    }
+}
+
+impl Question for FromRoman {
+    fn answer(&self, answer: &str) -> Outcome {
+        match place_value::parse(answer) {
+            Ok(answer) => {
+                if answer == self.number {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Exercise, RomanNumerals};
+
+    pub fn roman_numerals_1() -> RomanNumerals {
+        Config {
+            min_val: 1,
+            max_val: 40,
+            exercises: vec![Exercise::ToRoman, Exercise::FromRoman],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn roman_numerals_2() -> RomanNumerals {
+        Config {
+            min_val: 1,
+            max_val: 4_000,
+            exercises: vec![Exercise::ToRoman, Exercise::FromRoman],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,189 @@
+use crate::topic::roman_numerals::{
+    parse_roman, presets, to_roman, Config, Exercise, RomanNumerals,
+};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::roman_numerals_1();
+    assert_eq!("roman numerals", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_to_roman() {
+    let module = RomanNumerals::try_from(Config {
+        min_val: 1,
+        max_val: 4_000,
+        exercises: vec![Exercise::ToRoman],
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(3_999, lim);
+        1_993
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you write this number in Roman numerals for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("1994"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'1994' does not appear to be a Roman numeral; write it using the letters I, V, X, L, C, D and M"
+                .into()
+        ),
+        question.answer("1994")
+    );
+    assert_eq!(
+        Outcome::Invalid(
+            "'MCMXCIIII' is not a valid Roman numeral; I cannot appear more than three times in a row"
+                .into()
+        ),
+        question.answer("MCMXCIIII")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("MCMXCVI"));
+    assert_eq!(Outcome::Correct, question.answer("MCMXCIV"));
+    assert_eq!(Outcome::Correct, question.answer(" mcmxciv "));
+}
+
+#[test]
+fn display_ask_answer_from_roman() {
+    let module = RomanNumerals::try_from(Config {
+        min_val: 1,
+        max_val: 40,
+        exercises: vec![Exercise::ToRoman, Exercise::FromRoman],
+    })
+    .unwrap();
+
+    let rand_nums = [1, 13];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(2, lim),
+            _ => assert_eq!(39, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you write this Roman numeral as a number for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("XIV"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'XIV' does not appear to be a valid natural number".into()),
+        question.answer("XIV")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("16"));
+    assert_eq!(Outcome::Correct, question.answer("14"));
+}
+
+#[test]
+fn to_roman_examples() {
+    for (number, expected) in [
+        (1, "I"),
+        (4, "IV"),
+        (9, "IX"),
+        (14, "XIV"),
+        (40, "XL"),
+        (90, "XC"),
+        (400, "CD"),
+        (900, "CM"),
+        (1_994, "MCMXCIV"),
+        (2_024, "MMXXIV"),
+        (3_999, "MMMCMXCIX"),
+    ] {
+        assert_eq!(expected, to_roman(number), "{number}");
+    }
+}
+
+#[test]
+fn parse_roman_inverts_to_roman() {
+    for number in 1..4_000 {
+        assert_eq!(Ok(number), parse_roman(&to_roman(number)), "{number}");
+    }
+}
+
+#[test]
+fn parse_roman_explains_malformed() {
+    for (answer, expected) in [
+        ("", "'' does not appear to be a Roman numeral; write it using the letters I, V, X, L, C, D and M"),
+        ("XIZ", "'XIZ' does not appear to be a Roman numeral; write it using the letters I, V, X, L, C, D and M"),
+        ("IIII", "'IIII' is not a valid Roman numeral; I cannot appear more than three times in a row"),
+        ("MMMM", "'MMMM' is not a valid Roman numeral; M cannot appear more than three times in a row"),
+        ("VV", "'VV' is not a valid Roman numeral; V is never repeated"),
+        ("LL", "'LL' is not a valid Roman numeral; L is never repeated"),
+        ("VX", "'VX' is not a valid Roman numeral; V cannot be placed before X to subtract"),
+        ("IL", "'IL' is not a valid Roman numeral; I cannot be placed before L to subtract"),
+        ("XM", "'XM' is not a valid Roman numeral; X cannot be placed before M to subtract"),
+        ("IIV", "'IIV' is not a valid Roman numeral; its letters are not in the standard order"),
+        ("IXI", "'IXI' is not a valid Roman numeral; its letters are not in the standard order"),
+        ("VIIII", "'VIIII' is not a valid Roman numeral; I cannot appear more than three times in a row"),
+        ("MMMCMM", "'MMMCMM' is not a valid Roman numeral; the largest Roman numeral is MMMCMXCIX (3999)"),
+    ] {
+        assert_eq!(Err(expected.into()), parse_roman(answer), "{answer}");
+    }
+}
+
+#[test]
+fn ask_within_range() {
+    let module = presets::roman_numerals_1();
+    let mut rand = StdRand::default();
+    for _ in 0..100 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let body = s.lines().nth(1).unwrap();
+        let number = parse_roman(body).unwrap_or_else(|_| body.parse().unwrap());
+        assert!((1..40).contains(&number), "{s}");
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = RomanNumerals::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        exercises: vec![Exercise::ToRoman],
+    });
+    assert_eq!("min_val must be at least 1", module.err().unwrap());
+
+    let module = RomanNumerals::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        exercises: vec![Exercise::ToRoman],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = RomanNumerals::try_from(Config {
+        min_val: 1,
+        max_val: 4_001,
+        exercises: vec![Exercise::ToRoman],
+    });
+    assert_eq!("max_val cannot exceed 4000", module.err().unwrap());
+
+    let module = RomanNumerals::try_from(Config {
+        min_val: 1,
+        max_val: 10,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::roman_numerals_1();
+    presets::roman_numerals_2();
+}
@@ -0,0 +1,312 @@
+//! Questions on rounding and estimation.
+
+use crate::topic::place_value::format_thousands;
+use crate::topic::{choose, decimals, place_value, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The rounding module.
+pub struct Rounding {
+    config: Config,
+}
+
+/// What a number is rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// The nearest multiple of the given power of ten; e.g., `Nearest(100)`.
+    Nearest(u32),
+
+    /// The given number of decimal places.
+    DecimalPlaces(u32),
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 6] = [
+            "ten",
+            "hundred",
+            "thousand",
+            "ten thousand",
+            "hundred thousand",
+            "million",
+        ];
+        match self {
+            Target::Nearest(unit) => write!(f, "the nearest {}", UNITS[unit.ilog10() as usize - 1]),
+            Target::DecimalPlaces(1) => write!(f, "1 decimal place"),
+            Target::DecimalPlaces(places) => write!(f, "{places} decimal places"),
+        }
+    }
+}
+
+/// Configuration for [`Rounding`].
+pub struct Config {
+    /// The smallest number that will be asked.
+    pub min_val: u32,
+
+    /// The largest number that will be asked.
+    pub max_val: u32,
+
+    /// The rounding targets to choose from.
+    pub targets: Vec<Target>,
+
+    /// Also ask to estimate a sum or difference by rounding its operands, and then to work out
+    /// the exact answer. Estimates use the [`Target::Nearest`] targets.
+    pub estimate: bool,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 1_000_000;
+        const MAX_UNIT: u32 = 1_000_000;
+        const MAX_PLACES: u32 = 3;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.targets.is_empty() {
+            return Err("at least one target must be specified".into());
+        }
+        for target in &self.targets {
+            match *target {
+                Target::Nearest(unit) => {
+                    if !(10..=MAX_UNIT).contains(&unit) || 10_u32.pow(unit.ilog10()) != unit {
+                        return Err(format!(
+                            "cannot round to the nearest {unit}; use a power of ten between 10 and {MAX_UNIT}"
+                        ));
+                    }
+                }
+                Target::DecimalPlaces(places) => {
+                    if places == 0 || places > MAX_PLACES {
+                        return Err(format!(
+                            "cannot round to {places} decimal places; use between 1 and {MAX_PLACES}"
+                        ));
+                    }
+                }
+            }
+        }
+        if self.estimate && self.units().is_empty() {
+            return Err("estimates require at least one Nearest target".into());
+        }
+        Ok(())
+    }
+
+    /// The units of the [`Target::Nearest`] targets.
+    fn units(&self) -> Vec<u32> {
+        self.targets
+            .iter()
+            .filter_map(|target| match target {
+                Target::Nearest(unit) => Some(*unit),
+                Target::DecimalPlaces(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Config> for Rounding {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Rounding {
+    fn topic_name(&self) -> String {
+        String::from("rounding")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let estimate = self.config.estimate && rand.next_range(0..2) == 1;
+        if estimate {
+            let units = self.config.units();
+            let unit = *choose(&units, rand);
+            let lhs = rand.next_range(self.config.min_val..self.config.max_val);
+            let rhs = rand.next_range(self.config.min_val..self.config.max_val);
+            let subtract = rand.next_range(0..2) == 1;
+            let (lhs, rhs) = if subtract && lhs < rhs {
+                (rhs, lhs)
+            } else {
+                (lhs, rhs)
+            };
+            Box::new(Estimate {
+                lhs,
+                rhs,
+                subtract,
+                unit,
+            })
+        } else {
+            let target = *choose(&self.config.targets, rand);
+            match target {
+                Target::Nearest(_) => {
+                    let number = rand.next_range(self.config.min_val..self.config.max_val);
+                    Box::new(Round {
+                        number: number.into(),
+                        places: 0,
+                        target,
+                    })
+                }
+                Target::DecimalPlaces(places) => {
+                    let scale = 10_u32.pow(places + 2);
+                    let whole = rand.next_range(self.config.min_val..self.config.max_val);
+                    let fraction = rand.next_range(0..scale);
+                    Box::new(Round {
+                        number: u64::from(whole) * u64::from(scale) + u64::from(fraction),
+                        places: places + 2,
+                        target,
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Rounds a number to the nearest multiple of `unit`, with halves rounded up.
+fn round(number: u64, unit: u64) -> u64 {
+    (number + unit / 2) / unit * unit
+}
+
+struct Round {
+    /// The number being rounded, scaled by `10^places`.
+    number: u64,
+
+    /// The number of decimal places in `number`.
+    places: u32,
+
+    target: Target,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you round this number for me.")?;
+        if self.places == 0 {
+            let number = format_thousands(u32::try_from(self.number).unwrap());
+            write!(f, "Round {number} to {}.", self.target)
+        } else {
+            let number = decimals::format_scaled(self.number, self.places);
+            write!(f, "Round {number} to {}.", self.target)
+        }
+    }
+}
+
+impl Question for Round {
+    fn answer(&self, answer: &str) -> Outcome {
+        match self.target {
+            Target::Nearest(unit) => match place_value::parse(answer) {
+                Ok(answer) => assess(u64::from(answer) == round(self.number, unit.into())),
+                Err(err) => Outcome::Invalid(err),
+            },
+            Target::DecimalPlaces(places) => match decimals::parse_scaled(answer, places) {
+                Ok(answer) => {
+                    let unit = 10_u64.pow(self.places - places);
+                    assess(answer == round(self.number, unit) / unit)
+                }
+                Err(err) => Outcome::Invalid(err),
+            },
+        }
+    }
+}
+
+struct Estimate {
+    lhs: u32,
+    rhs: u32,
+    subtract: bool,
+    unit: u32,
+}
+
+impl Estimate {
+    fn expected(&self) -> (u64, u64) {
+        let (lhs, rhs, unit) = (
+            u64::from(self.lhs),
+            u64::from(self.rhs),
+            u64::from(self.unit),
+        );
+        if self.subtract {
+            (round(lhs, unit) - round(rhs, unit), lhs - rhs)
+        } else {
+            (round(lhs, unit) + round(rhs, unit), lhs + rhs)
+        }
+    }
+}
+
+impl Display for Estimate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operator = if self.subtract { "–" } else { "+" };
+        writeln!(
+            f,
+            "Can you estimate this by rounding each number to {}, then work out the exact answer.",
+            Target::Nearest(self.unit)
+        )?;
+        writeln!(
+            f,
+            "Write both answers separated by a semicolon, e.g., '900; 899'."
+        )?;
+        write!(f, "{} {operator} {} = ?", self.lhs, self.rhs)
+    }
+}
+
+impl Question for Estimate {
+    fn answer(&self, answer: &str) -> Outcome {
+        let answers = answer
+            .split(';')
+            .map(place_value::parse)
+            .collect::<Result<Vec<_>, _>>();
+        match answers.as_deref() {
+            Ok(&[estimate, exact]) => {
+                assess((u64::from(estimate), u64::from(exact)) == self.expected())
+            }
+            _ => Outcome::Invalid(format!(
+                "'{answer}' does not appear to be a valid answer; write the estimate and the exact answer separated by a semicolon (e.g., '900; 899')"
+            )),
+        }
+    }
+}
+
+fn assess(correct: bool) -> Outcome {
+    if correct {
+        Outcome::Correct
+    } else {
+        Outcome::Incorrect
+    }
+}
+
+pub mod presets {
+    use super::{Config, Rounding, Target};
+
+    pub fn rounding_1() -> Rounding {
+        Config {
+            min_val: 10,
+            max_val: 1_000,
+            targets: vec![Target::Nearest(10), Target::Nearest(100)],
+            estimate: false,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn rounding_2() -> Rounding {
+        Config {
+            min_val: 10,
+            max_val: 10_000,
+            targets: vec![
+                Target::Nearest(10),
+                Target::Nearest(100),
+                Target::Nearest(1_000),
+                Target::DecimalPlaces(1),
+                Target::DecimalPlaces(2),
+            ],
+            estimate: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
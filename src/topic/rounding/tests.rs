@@ -0,0 +1,231 @@
+use crate::topic::rounding::{presets, round, Config, Rounding, Target};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::rounding_1();
+    assert_eq!("rounding", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_nearest() {
+    let module = Rounding::try_from(Config {
+        min_val: 1_000,
+        max_val: 10_000,
+        targets: vec![Target::Nearest(10), Target::Nearest(100)],
+        estimate: false,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 3_350];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(2, lim);
+        } else {
+            assert_eq!(9_000, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you round this number for me."), "{}", s);
+    assert!(s.contains("Round 4,350 to the nearest hundred."), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'foo' does not appear to be a valid natural number".into()),
+        question.answer("foo")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("4300"));
+    assert_eq!(Outcome::Incorrect, question.answer("4350"));
+    assert_eq!(Outcome::Correct, question.answer("4400"));
+    assert_eq!(Outcome::Correct, question.answer("4,400"));
+}
+
+#[test]
+fn display_ask_answer_decimal_places() {
+    let module = Rounding::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        targets: vec![Target::DecimalPlaces(2)],
+        estimate: false,
+    })
+    .unwrap();
+
+    let rand_nums = [3, 1_459];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(10, lim);
+        } else {
+            assert_eq!(10_000, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Round 3.1459 to 2 decimal places."), "{}", s);
+
+    assert!(matches!(question.answer("three"), Outcome::Invalid(_)));
+    assert_eq!(Outcome::Incorrect, question.answer("3.14"));
+    assert_eq!(Outcome::Incorrect, question.answer("3.1"));
+    assert_eq!(Outcome::Correct, question.answer("3.15"));
+    assert_eq!(Outcome::Correct, question.answer("3,15"));
+    assert_eq!(
+        Outcome::Invalid("'3.149' has more than 2 decimal places".into()),
+        question.answer("3.149")
+    );
+}
+
+#[test]
+fn display_ask_answer_estimate() {
+    let module = Rounding::try_from(Config {
+        min_val: 100,
+        max_val: 1_000,
+        targets: vec![Target::Nearest(100), Target::DecimalPlaces(1)],
+        estimate: true,
+    })
+    .unwrap();
+
+    for (subtract, expected_display, expected_answer, wrong_answer) in [
+        (0, "387 + 512 = ?", "900; 899", "899; 899"),
+        (1, "512 – 387 = ?", "100; 125", "125; 100"),
+    ] {
+        let rand_nums = [1, 287, 412, subtract];
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(
+            s.contains("rounding each number to the nearest hundred"),
+            "{}",
+            s
+        );
+        assert!(s.contains(expected_display), "{}", s);
+
+        assert!(matches!(question.answer("900"), Outcome::Invalid(_)));
+        assert!(matches!(question.answer("900, 899"), Outcome::Invalid(_)));
+        assert!(matches!(question.answer("900; 1; 2"), Outcome::Invalid(_)));
+        assert_eq!(Outcome::Incorrect, question.answer(wrong_answer));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn answer_estimate_with_thousands_separators() {
+    let module = Rounding::try_from(Config {
+        min_val: 1_000,
+        max_val: 100_000,
+        targets: vec![Target::Nearest(1_000)],
+        estimate: true,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 5_199, 3_799, 0];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("6199 + 4799 = ?"), "{}", s);
+
+    assert!(matches!(
+        question.answer("11,000, 10,998"),
+        Outcome::Invalid(_)
+    ));
+    assert_eq!(Outcome::Incorrect, question.answer("10,000; 10,998"));
+    assert_eq!(Outcome::Correct, question.answer("11,000; 10,998"));
+    assert_eq!(Outcome::Correct, question.answer("11000;10998"));
+}
+
+#[test]
+fn round_half_up() {
+    assert_eq!(4_400, round(4_350, 100));
+    assert_eq!(4_300, round(4_349, 100));
+    assert_eq!(0, round(4, 10));
+    assert_eq!(10, round(5, 10));
+}
+
+#[test]
+fn invalid_config() {
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 10,
+        targets: vec![Target::Nearest(10)],
+        estimate: false,
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 1_000_001,
+        targets: vec![Target::Nearest(10)],
+        estimate: false,
+    });
+    assert_eq!("max_val cannot exceed 1000000", module.err().unwrap());
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        targets: vec![],
+        estimate: false,
+    });
+    assert_eq!(
+        "at least one target must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        targets: vec![Target::Nearest(50)],
+        estimate: false,
+    });
+    assert_eq!(
+        "cannot round to the nearest 50; use a power of ten between 10 and 1000000",
+        module.err().unwrap()
+    );
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        targets: vec![Target::Nearest(1)],
+        estimate: false,
+    });
+    assert_eq!(
+        "cannot round to the nearest 1; use a power of ten between 10 and 1000000",
+        module.err().unwrap()
+    );
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        targets: vec![Target::DecimalPlaces(4)],
+        estimate: false,
+    });
+    assert_eq!(
+        "cannot round to 4 decimal places; use between 1 and 3",
+        module.err().unwrap()
+    );
+
+    let module = Rounding::try_from(Config {
+        min_val: 10,
+        max_val: 100,
+        targets: vec![Target::DecimalPlaces(1)],
+        estimate: true,
+    });
+    assert_eq!(
+        "estimates require at least one Nearest target",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::rounding_1();
+    presets::rounding_2();
+}
@@ -0,0 +1,248 @@
+//! Questions on completing number sequences.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The sequences module.
+pub struct Sequences {
+    config: Config,
+}
+
+/// The families of sequences that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Family {
+    /// Each term adds a fixed step to the one before; e.g., `4, 9, 14, 19, 24`.
+    Arithmetic,
+
+    /// Each term multiplies the one before by a fixed ratio of at least 2; e.g., `3, 6, 12, 24`.
+    Geometric,
+
+    /// Consecutive square numbers; e.g., `9, 16, 25, 36`.
+    Square,
+
+    /// Each term is the sum of the two before; e.g., `2, 5, 7, 12, 19`.
+    Fibonacci,
+}
+
+/// Configuration for [`Sequences`].
+pub struct Config {
+    /// The sequence families to choose from.
+    pub families: Vec<Family>,
+
+    /// The smallest starting term (or, for square numbers, the smallest starting root).
+    pub min_start: u32,
+
+    /// The largest starting term.
+    pub max_start: u32,
+
+    /// The smallest step between terms in arithmetic sequences, or ratio in geometric sequences.
+    pub min_step: u32,
+
+    /// The largest step or ratio.
+    pub max_step: u32,
+
+    /// The number of terms shown, including the blank.
+    pub length: u32,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MIN_LENGTH: u32 = 4;
+        const MAX_LENGTH: u32 = 10;
+        const MAX_TERM: u64 = 1_000_000_000;
+        if self.families.is_empty() {
+            return Err("at least one sequence family must be specified".into());
+        }
+        if self.min_start >= self.max_start {
+            return Err("min_start must be less than max_start".into());
+        }
+        if self.min_step >= self.max_step {
+            return Err("min_step must be less than max_step".into());
+        }
+        if self.length < MIN_LENGTH || self.length > MAX_LENGTH {
+            return Err(format!(
+                "length must be between {MIN_LENGTH} and {MAX_LENGTH}"
+            ));
+        }
+        if self.families.contains(&Family::Geometric) && self.geometric_starts().is_empty() {
+            return Err("geometric sequences require a start of at least 1 below max_start".into());
+        }
+        if self.families.contains(&Family::Geometric) && self.ratios().is_empty() {
+            return Err("geometric sequences require a ratio of at least 2 below max_step".into());
+        }
+        let largest = self
+            .families
+            .iter()
+            .map(|&family| {
+                let start = u64::from(self.max_start - 1);
+
+                // both starting terms of a Fibonacci-like sequence are drawn from the starts
+                let step = match family {
+                    Family::Fibonacci => start,
+                    _ => u64::from(self.max_step - 1),
+                };
+                terms(family, start, step, self.length)
+                    .and_then(|terms| terms.last().copied())
+                    .unwrap_or(u64::MAX)
+            })
+            .max()
+            .unwrap();
+        if largest > MAX_TERM {
+            return Err(format!("the terms of a sequence cannot exceed {MAX_TERM}"));
+        }
+        Ok(())
+    }
+
+    /// The range of starting terms in geometric sequences, which exclude zero.
+    fn geometric_starts(&self) -> std::ops::Range<u32> {
+        self.min_start.max(1)..self.max_start
+    }
+
+    /// The range of ratios in geometric sequences.
+    fn ratios(&self) -> std::ops::Range<u32> {
+        self.min_step.max(2)..self.max_step
+    }
+}
+
+impl TryFrom<Config> for Sequences {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+/// Generates the terms of a sequence from its first term and step (or, for Fibonacci-like
+/// sequences, its first two terms). Returns `None` on overflow.
+fn terms(family: Family, start: u64, step: u64, length: u32) -> Option<Vec<u64>> {
+    let mut terms = Vec::with_capacity(length as usize);
+    for index in 0..u64::from(length) {
+        let term = match family {
+            Family::Arithmetic => start.checked_add(index.checked_mul(step)?)?,
+            Family::Geometric => {
+                start.checked_mul(step.checked_pow(u32::try_from(index).ok()?)?)?
+            }
+            Family::Square => (start + index).checked_pow(2)?,
+            Family::Fibonacci => match index {
+                0 => start,
+                1 => step,
+                _ => terms[terms.len() - 2] + terms[terms.len() - 1],
+            },
+        };
+        terms.push(term);
+    }
+    Some(terms)
+}
+
+impl Module for Sequences {
+    fn topic_name(&self) -> String {
+        String::from("sequences")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let family = *choose(&self.config.families, rand);
+        let starts = self.config.min_start..self.config.max_start;
+        let (start, step) = match family {
+            Family::Arithmetic => (
+                rand.next_range(starts),
+                rand.next_range(self.config.min_step..self.config.max_step),
+            ),
+            Family::Geometric => (
+                rand.next_range(self.config.geometric_starts()),
+                rand.next_range(self.config.ratios()),
+            ),
+            Family::Square => (rand.next_range(starts), 0),
+            Family::Fibonacci => (rand.next_range(starts.clone()), rand.next_range(starts)),
+        };
+        let terms = terms(family, start.into(), step.into(), self.config.length).unwrap();
+        let blank = rand.next_range(0..self.config.length);
+        Box::new(Completion { terms, blank })
+    }
+}
+
+/// A sequence with one term left blank.
+struct Completion {
+    terms: Vec<u64>,
+    blank: u32,
+}
+
+impl Display for Completion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you fill in the missing number for me.")?;
+        let terms = self
+            .terms
+            .iter()
+            .enumerate()
+            .map(|(index, term)| {
+                if index == self.blank as usize {
+                    String::from("?")
+                } else {
+                    term.to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", terms.join(", "))
+    }
+}
+
+impl Question for Completion {
+    fn answer(&self, answer: &str) -> Outcome {
+        match u64::from_str(answer.trim()) {
+            Ok(answer) => {
+                if answer == self.terms[self.blank as usize] {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(_) => Outcome::Invalid(format!(
+                "'{answer}' does not appear to be a valid natural number"
+            )),
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Family, Sequences};
+
+    pub fn sequences_1() -> Sequences {
+        Config {
+            families: vec![Family::Arithmetic],
+            min_start: 0,
+            max_start: 20,
+            min_step: 1,
+            max_step: 11,
+            length: 5,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn sequences_2() -> Sequences {
+        Config {
+            families: vec![
+                Family::Arithmetic,
+                Family::Geometric,
+                Family::Square,
+                Family::Fibonacci,
+            ],
+            min_start: 1,
+            max_start: 20,
+            min_step: 2,
+            max_step: 13,
+            length: 5,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
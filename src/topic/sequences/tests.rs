@@ -0,0 +1,221 @@
+use crate::topic::sequences::{presets, Config, Family, Sequences};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+fn config(families: Vec<Family>) -> Config {
+    Config {
+        families,
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 11,
+        length: 5,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::sequences_1();
+    assert_eq!("sequences", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_arithmetic() {
+    let module = Sequences::try_from(config(vec![Family::Arithmetic])).unwrap();
+
+    let rand_nums = [3, 4, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(19, lim),
+            1 => assert_eq!(10, lim),
+            _ => assert_eq!(5, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you fill in the missing number for me."),
+        "{}",
+        s
+    );
+    assert!(s.ends_with("4, 9, 14, ?, 24"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'foo' does not appear to be a valid natural number".into()),
+        question.answer("foo")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("20"));
+    assert_eq!(Outcome::Correct, question.answer("19"));
+}
+
+#[test]
+fn display_ask_answer_families() {
+    let module = Sequences::try_from(config(vec![
+        Family::Arithmetic,
+        Family::Geometric,
+        Family::Square,
+        Family::Fibonacci,
+    ]))
+    .unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        (vec![1, 2, 0, 4], "3, 6, 12, 24, ?", "48"),
+        (vec![2, 2, 0], "?, 16, 25, 36, 49", "9"),
+        (vec![3, 1, 4, 2], "2, 5, ?, 12, 19", "7"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() == 0 {
+                assert_eq!(4, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.ends_with(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Sequences::try_from(Config {
+        families: vec![],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 11,
+        length: 5,
+    });
+    assert_eq!(
+        "at least one sequence family must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Arithmetic],
+        min_start: 1,
+        max_start: 1,
+        min_step: 1,
+        max_step: 11,
+        length: 5,
+    });
+    assert_eq!(
+        "min_start must be less than max_start",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Arithmetic],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 1,
+        length: 5,
+    });
+    assert_eq!("min_step must be less than max_step", module.err().unwrap());
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Arithmetic],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 11,
+        length: 3,
+    });
+    assert_eq!("length must be between 4 and 10", module.err().unwrap());
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Arithmetic],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 11,
+        length: 11,
+    });
+    assert_eq!("length must be between 4 and 10", module.err().unwrap());
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Geometric],
+        min_start: 0,
+        max_start: 1,
+        min_step: 1,
+        max_step: 11,
+        length: 5,
+    });
+    assert_eq!(
+        "geometric sequences require a start of at least 1 below max_start",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Geometric],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 2,
+        length: 5,
+    });
+    assert_eq!(
+        "geometric sequences require a ratio of at least 2 below max_step",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Geometric],
+        min_start: 1,
+        max_start: 20,
+        min_step: 1,
+        max_step: 100,
+        length: 10,
+    });
+    assert_eq!(
+        "the terms of a sequence cannot exceed 1000000000",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Fibonacci],
+        min_start: 1,
+        max_start: 200_000_000,
+        min_step: 1,
+        max_step: 11,
+        length: 6,
+    });
+    assert_eq!(
+        "the terms of a sequence cannot exceed 1000000000",
+        module.err().unwrap()
+    );
+
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Fibonacci],
+        min_start: 1,
+        max_start: 125_000_002,
+        min_step: 1,
+        max_step: 11,
+        length: 6,
+    });
+    assert_eq!(
+        "the terms of a sequence cannot exceed 1000000000",
+        module.err().unwrap()
+    );
+
+    // the sixth term is 8 × 125000000 when both starting terms are the largest start
+    let module = Sequences::try_from(Config {
+        families: vec![Family::Fibonacci],
+        min_start: 1,
+        max_start: 125_000_001,
+        min_step: 1,
+        max_step: 11,
+        length: 6,
+    });
+    assert!(module.is_ok());
+}
+
+#[test]
+fn presets() {
+    presets::sequences_1();
+    presets::sequences_2();
+}
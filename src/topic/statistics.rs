@@ -0,0 +1,298 @@
+//! Questions on the mean, median, mode and range of a data set.
+
+use crate::topic::{choose, decimals, gcd, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The statistics module.
+pub struct Statistics {
+    config: Config,
+}
+
+/// The measures that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    /// The mean, which is always whole or a terminating decimal; see [`Config::mean_places`].
+    Mean,
+
+    /// The median, which is halfway between the two middle values of an even-sized data set.
+    Median,
+
+    /// The mode. Data sets asked for their mode always have a single mode.
+    Mode,
+
+    /// The difference between the largest and the smallest value.
+    Range,
+}
+
+/// Configuration for [`Statistics`].
+pub struct Config {
+    /// The smallest value in a data set.
+    pub min_val: u32,
+
+    /// The largest value in a data set.
+    pub max_val: u32,
+
+    /// The smallest number of values in a data set.
+    pub min_size: u32,
+
+    /// The largest number of values in a data set.
+    pub max_size: u32,
+
+    /// The largest number of decimal places in the mean. When `0`, the mean is always whole.
+    pub mean_places: u32,
+
+    /// The measures to choose from.
+    pub measures: Vec<Measure>,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_MAX_VAL: u32 = 10_000;
+        const MIN_MIN_SIZE: u32 = 3;
+        const MAX_MAX_SIZE: u32 = 12;
+        const MAX_MEAN_PLACES: u32 = 3;
+        if self.min_val >= self.max_val {
+            return Err("min_val must be less than max_val".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        if self.min_size < MIN_MIN_SIZE {
+            return Err(format!("min_size must be at least {MIN_MIN_SIZE}"));
+        }
+        if self.min_size > self.max_size {
+            return Err("min_size cannot exceed max_size".into());
+        }
+        if self.max_size > MAX_MAX_SIZE {
+            return Err(format!("max_size cannot exceed {MAX_MAX_SIZE}"));
+        }
+
+        // Ensures that there are enough distinct values for a single mode, and a final value
+        // that makes the mean come out.
+        if self.max_val - self.min_val < self.max_size {
+            return Err("max_val must exceed min_val by at least max_size".into());
+        }
+        if self.mean_places > MAX_MEAN_PLACES {
+            return Err(format!("mean_places cannot exceed {MAX_MEAN_PLACES}"));
+        }
+        if self.measures.is_empty() {
+            return Err("at least one measure must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Statistics {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Statistics {
+    /// Generates values whose mean has no more than [`Config::mean_places`] decimal places, by
+    /// choosing the last value to make the sum divisible.
+    fn mean_values(&self, size: u32, rand: &mut dyn RandRange<u32>) -> Vec<u32> {
+        let (min_val, max_val) = (self.config.min_val, self.config.max_val);
+        let mut values = (1..size)
+            .map(|_| rand.next_range(min_val..max_val))
+            .collect::<Vec<_>>();
+        let scale = 10_u32.pow(self.config.mean_places);
+        let modulus = size / gcd(size, scale);
+        let sum = values.iter().sum::<u32>() + min_val;
+        let first = min_val + (modulus - sum % modulus) % modulus;
+        let candidates = (max_val - 1 - first) / modulus + 1;
+        let last = if candidates == 1 {
+            first
+        } else {
+            first + rand.next_range(0..candidates) * modulus
+        };
+        values.push(last);
+        values
+    }
+
+    /// Generates values with a single mode, which is repeated, while every other value appears
+    /// once.
+    fn mode_values(&self, size: u32, rand: &mut dyn RandRange<u32>) -> Vec<u32> {
+        let (min_val, max_val) = (self.config.min_val, self.config.max_val);
+        let mode = rand.next_range(min_val..max_val);
+        let repeats = if size / 2 <= 2 {
+            2
+        } else {
+            rand.next_range(2..size / 2 + 1)
+        };
+        let mut taken = vec![mode];
+        for _ in repeats..size {
+            let available = max_val - min_val - u32::try_from(taken.len()).unwrap();
+            let mut value = min_val + rand.next_range(0..available);
+            taken.sort_unstable();
+            for &other in &taken {
+                if other <= value {
+                    value += 1;
+                }
+            }
+            taken.push(value);
+        }
+        let mut values = vec![mode; repeats as usize - 1];
+        values.append(&mut taken);
+        shuffle(&mut values, rand);
+        values
+    }
+}
+
+fn shuffle(values: &mut [u32], rand: &mut dyn RandRange<u32>) {
+    for index in (1..values.len()).rev() {
+        let other = rand.next_range(0..u32::try_from(index).unwrap() + 1);
+        values.swap(index, other as usize);
+    }
+}
+
+impl Module for Statistics {
+    fn topic_name(&self) -> String {
+        String::from("statistics")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let measure = *choose(&self.config.measures, rand);
+        let size = if self.config.min_size == self.config.max_size {
+            self.config.min_size
+        } else {
+            rand.next_range(self.config.min_size..self.config.max_size + 1)
+        };
+        let values = match measure {
+            Measure::Mean => self.mean_values(size, rand),
+            Measure::Mode => self.mode_values(size, rand),
+            Measure::Median | Measure::Range => (0..size)
+                .map(|_| rand.next_range(self.config.min_val..self.config.max_val))
+                .collect(),
+        };
+        Box::new(Summary {
+            values,
+            measure,
+            mean_places: self.config.mean_places,
+        })
+    }
+}
+
+/// Summarising a data set by one of its measures.
+struct Summary {
+    values: Vec<u32>,
+    measure: Measure,
+    mean_places: u32,
+}
+
+impl Summary {
+    /// The expected answer, scaled by `10^places`, alongside the number of places.
+    fn expected(&self) -> (u64, u32) {
+        let mut sorted = self
+            .values
+            .iter()
+            .map(|&value| u64::from(value))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        match self.measure {
+            Measure::Mean => {
+                let scale = 10_u64.pow(self.mean_places);
+                let sum = sorted.iter().sum::<u64>();
+                (sum * scale / count as u64, self.mean_places)
+            }
+            Measure::Median => {
+                let middle = if count.is_multiple_of(2) {
+                    (sorted[count / 2 - 1] + sorted[count / 2]) * 5
+                } else {
+                    sorted[count / 2] * 10
+                };
+                (middle, 1)
+            }
+            Measure::Mode => {
+                let mode = sorted
+                    .iter()
+                    .copied()
+                    .max_by_key(|&value| sorted.iter().filter(|&&other| other == value).count())
+                    .unwrap();
+                (mode, 0)
+            }
+            Measure::Range => (sorted[count - 1] - sorted[0], 0),
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let measure = match self.measure {
+            Measure::Mean => "mean",
+            Measure::Median => "median",
+            Measure::Mode => "mode",
+            Measure::Range => "range",
+        };
+        writeln!(f, "Can you find the {measure} of these numbers for me.")?;
+        let values = self
+            .values
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "{}", values.join(", "))
+    }
+}
+
+impl Question for Summary {
+    fn answer(&self, answer: &str) -> Outcome {
+        let (expected, places) = self.expected();
+        match decimals::parse_scaled(answer, places) {
+            Ok(answer) => {
+                if answer == expected {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Measure, Statistics};
+
+    pub fn statistics_1() -> Statistics {
+        Config {
+            min_val: 0,
+            max_val: 20,
+            min_size: 3,
+            max_size: 7,
+            mean_places: 0,
+            measures: vec![Measure::Mean, Measure::Mode, Measure::Range],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn statistics_2() -> Statistics {
+        Config {
+            min_val: 0,
+            max_val: 100,
+            min_size: 4,
+            max_size: 10,
+            mean_places: 1,
+            measures: vec![
+                Measure::Mean,
+                Measure::Median,
+                Measure::Mode,
+                Measure::Range,
+            ],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,278 @@
+use crate::topic::statistics::{presets, Config, Measure, Statistics};
+use crate::topic::{Module, Outcome};
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+fn config(measures: Vec<Measure>, mean_places: u32) -> Config {
+    Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 4,
+        max_size: 4,
+        mean_places,
+        measures,
+    }
+}
+
+#[test]
+fn name() {
+    let module = presets::statistics_1();
+    assert_eq!("statistics", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_mean() {
+    let module = Statistics::try_from(config(vec![Measure::Mean], 0)).unwrap();
+
+    let rand_nums = [3, 7, 9, 2];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0..=2 => assert_eq!(20, lim),
+            _ => assert_eq!(5, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you find the mean of these numbers for me."),
+        "{}",
+        s
+    );
+    assert!(s.ends_with("3, 7, 9, 9"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid("'seven' does not appear to be a valid decimal number".into()),
+        question.answer("seven")
+    );
+    assert_eq!(
+        Outcome::Invalid("'7.5' should be a whole number".into()),
+        question.answer("7.5")
+    );
+    assert_eq!(
+        Outcome::Invalid("'7.04' should be a whole number".into()),
+        question.answer("7.04")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("8"));
+    assert_eq!(Outcome::Correct, question.answer("7"));
+    assert_eq!(Outcome::Correct, question.answer("7.0"));
+}
+
+#[test]
+fn display_ask_answer_mean_decimal() {
+    let module = Statistics::try_from(config(vec![Measure::Mean], 1)).unwrap();
+
+    let rand_nums = [3, 7, 8, 0];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 3 {
+            assert_eq!(10, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.ends_with("3, 7, 8, 0"), "{}", s);
+    assert_eq!(Outcome::Incorrect, question.answer("4"));
+    assert_eq!(
+        Outcome::Invalid("'4.55' has more than 1 decimal place".into()),
+        question.answer("4.55")
+    );
+    assert_eq!(Outcome::Correct, question.answer("4.5"));
+    assert_eq!(Outcome::Correct, question.answer("4.50"));
+}
+
+#[test]
+fn display_ask_answer_median_range() {
+    let module = Statistics::try_from(config(vec![Measure::Median, Measure::Range], 0)).unwrap();
+
+    for (measure, expected_display, expected_answer) in [(0, "median", "4.5"), (1, "range", "8")] {
+        let rand_nums = [measure, 5, 1, 9, 4];
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(
+            s.contains(&format!(
+                "Can you find the {expected_display} of these numbers for me."
+            )),
+            "{}",
+            s
+        );
+        assert!(s.ends_with("5, 1, 9, 4"), "{}", s);
+        assert_eq!(Outcome::Incorrect, question.answer("5"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_mode() {
+    let module = Statistics::try_from(Config {
+        min_size: 5,
+        max_size: 5,
+        ..config(vec![Measure::Mode], 0)
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            3
+        } else {
+            0
+        }
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you find the mode of these numbers for me."),
+        "{}",
+        s
+    );
+    assert!(s.ends_with("0, 1, 3, 2, 3"), "{}", s);
+    assert_eq!(Outcome::Incorrect, question.answer("2"));
+    assert_eq!(Outcome::Correct, question.answer("3"));
+}
+
+#[test]
+fn guarantees() {
+    let mut rand = StdRand::default();
+    for mean_places in 0..=2 {
+        for measure in [Measure::Mean, Measure::Mode] {
+            let module = Statistics::try_from(Config {
+                min_val: 0,
+                max_val: 50,
+                min_size: 3,
+                max_size: 12,
+                mean_places,
+                measures: vec![measure],
+            })
+            .unwrap();
+
+            for _ in 0..100 {
+                let question = module.ask(&mut rand);
+                let s = format!("{}", question);
+                let values = s
+                    .lines()
+                    .last()
+                    .unwrap()
+                    .split(", ")
+                    .map(|value| value.parse::<u32>().unwrap())
+                    .collect::<Vec<_>>();
+                assert!((3..=12).contains(&values.len()), "{}", s);
+                assert!(values.iter().all(|&value| value < 50), "{}", s);
+                let count = |value| values.iter().filter(|&&other| other == value).count();
+                if measure == Measure::Mean {
+                    let sum = values.iter().sum::<u32>() * 10_u32.pow(mean_places);
+                    assert_eq!(0, sum % u32::try_from(values.len()).unwrap(), "{}", s);
+                } else {
+                    let mode_count = values.iter().map(|&value| count(value)).max().unwrap();
+                    let modes = values
+                        .iter()
+                        .filter(|&&value| count(value) == mode_count)
+                        .count();
+                    assert!(mode_count >= 2, "{}", s);
+                    assert_eq!(mode_count, modes, "{}", s);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 0,
+        min_size: 4,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("min_val must be less than max_val", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 10_001,
+        min_size: 4,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("max_val cannot exceed 10000", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 2,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("min_size must be at least 3", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 5,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("min_size cannot exceed max_size", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 4,
+        max_size: 13,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("max_size cannot exceed 12", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 3,
+        min_size: 4,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!(
+        "max_val must exceed min_val by at least max_size",
+        module.err().unwrap()
+    );
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 4,
+        max_size: 4,
+        mean_places: 4,
+        measures: vec![Measure::Mean],
+    });
+    assert_eq!("mean_places cannot exceed 3", module.err().unwrap());
+
+    let module = Statistics::try_from(Config {
+        min_val: 0,
+        max_val: 20,
+        min_size: 4,
+        max_size: 4,
+        mean_places: 0,
+        measures: vec![],
+    });
+    assert_eq!(
+        "at least one measure must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::statistics_1();
+    presets::statistics_2();
+}
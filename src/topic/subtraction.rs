@@ -1,6 +1,7 @@
 //! Questions on subtraction.
 
-use crate::topic::{Module, Outcome, Question};
+use crate::topic::regrouping::{pick, Operation, Order, PairSampler, Regrouping};
+use crate::topic::{format_operand, next_signed, Module, Outcome, Question, Unknown};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use tinyrand::RandRange;
@@ -8,18 +9,32 @@ use tinyrand::RandRange;
 /// The subtraction module.
 pub struct Subtraction {
     config: Config,
+
+    /// Generates the operands when [`Config::borrows`] is constrained. Each sampler is paired
+    /// with a flag indicating whether it generates the operands in reverse order; i.e., for
+    /// a negative difference.
+    samplers: Vec<(PairSampler, bool)>,
 }
 
 /// Configuration for [`Subtraction`].
 pub struct Config {
-    /// The smallest number that will be asked.
-    pub min_val: u32,
+    /// The smallest number that will be asked. If negative, the subtrahend may also be as small as
+    /// this; otherwise, the subtrahend is never negative.
+    pub min_val: i32,
 
     /// The largest number that will be asked.
-    pub max_val: u32,
+    pub max_val: i32,
 
     /// Allow the difference to be negative.
     pub allow_negative: bool,
+
+    /// The part of the difference that the student must find.
+    pub unknown: Unknown,
+
+    /// The number of borrows that the difference requires when subtracted in columns. For a
+    /// negative difference, the borrows are those of subtracting the smaller number from the
+    /// larger. Borrows may only be constrained for non-negative numbers.
+    pub borrows: Regrouping,
 }
 
 impl Config {
@@ -28,14 +43,56 @@ impl Config {
     /// # Errors
     /// If the config is invalid.
     pub fn validate(&self) -> Result<(), String> {
-        const MAX_MAX_VAL: u32 = u32::MAX << 1;
+        self.validate_and_sample().map(|_| ())
+    }
+
+    /// Validates the config, returning the samplers for constrained borrows, if any are needed.
+    fn validate_and_sample(&self) -> Result<Vec<(PairSampler, bool)>, String> {
         if self.min_val >= self.max_val {
             return Err("min_val must be less than max_val".into());
         }
-        if self.max_val > MAX_MAX_VAL {
-            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        if i64::from(self.max_val - 1) - i64::from(self.min_subtrahend()) > i64::from(i32::MAX) {
+            return Err(format!(
+                "the difference between min_val and max_val cannot exceed {}",
+                i32::MAX
+            ));
+        }
+        if self.borrows == Regrouping::Any {
+            return Ok(vec![]);
+        }
+        let Ok(min_val) = u32::try_from(self.min_val) else {
+            return Err("borrows can only be constrained for non-negative numbers".into());
+        };
+        let max_val = u32::try_from(self.max_val - 1).unwrap();
+        let lhs = min_val..=max_val;
+        let rhs = 0..=max_val;
+        let mut samplers = vec![(
+            PairSampler::new(
+                Operation::Subtract,
+                Order::GreaterOrEqual,
+                lhs.clone(),
+                rhs.clone(),
+                self.borrows,
+            ),
+            false,
+        )];
+        if self.allow_negative {
+            samplers.push((
+                PairSampler::new(Operation::Subtract, Order::Greater, rhs, lhs, self.borrows),
+                true,
+            ));
+        }
+        if samplers.iter().all(|(sampler, _)| sampler.total() == 0) {
+            return Err(
+                "no differences between min_val and max_val satisfy the borrows constraint".into(),
+            );
         }
-        Ok(())
+        Ok(samplers)
+    }
+
+    /// The smallest subtrahend that will be asked.
+    fn min_subtrahend(&self) -> i32 {
+        self.min_val.min(0)
     }
 }
 
@@ -43,8 +100,8 @@ impl TryFrom<Config> for Subtraction {
     type Error = String;
 
     fn try_from(config: Config) -> Result<Self, Self::Error> {
-        config.validate()?;
-        Ok(Self { config })
+        let samplers = config.validate_and_sample()?;
+        Ok(Self { config, samplers })
     }
 }
 
@@ -54,27 +111,73 @@ impl Module for Subtraction {
     }
 
     fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
-        let lhs = rand.next_range(self.config.min_val..self.config.max_val);
-        let rhs = if self.config.allow_negative {
-            rand.next_range(0..self.config.max_val)
-        } else if lhs == 0 {
-            0
+        let (lhs, rhs) = if self.samplers.is_empty() {
+            let min_rhs = self.config.min_subtrahend();
+            let lhs = next_signed(rand, self.config.min_val..self.config.max_val);
+            let rhs = if self.config.allow_negative {
+                next_signed(rand, min_rhs..self.config.max_val)
+            } else if lhs == min_rhs {
+                min_rhs
+            } else {
+                next_signed(rand, min_rhs..lhs)
+            };
+            (lhs, rhs)
         } else {
-            rand.next_range(0..lhs)
+            let weights = self
+                .samplers
+                .iter()
+                .map(|(sampler, _)| sampler.total())
+                .collect::<Vec<_>>();
+            let (sampler, reversed) = &self.samplers[pick(&weights, rand)];
+            let (x, y) = sampler.sample(rand);
+            let (x, y) = (i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
+            if *reversed {
+                (y, x)
+            } else {
+                (x, y)
+            }
         };
-        Box::new(Difference { lhs, rhs })
+        let unknown = self.config.unknown.position(2, rand);
+        Box::new(Difference { lhs, rhs, unknown })
     }
 }
 
 struct Difference {
-    lhs: u32,
-    rhs: u32,
+    lhs: i32,
+    rhs: i32,
+
+    /// The position of the hidden term: `0` for `lhs`, `1` for `rhs` and `2` for the difference.
+    unknown: u32,
+}
+
+impl Difference {
+    fn terms(&self) -> [i32; 3] {
+        [self.lhs, self.rhs, self.lhs - self.rhs]
+    }
 }
 
 impl Display for Difference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Can you subtract these two numbers for me.")?;
-        write!(f, "{} – {} = ?", self.lhs, self.rhs)
+        let terms = self
+            .terms()
+            .iter()
+            .enumerate()
+            .map(|(position, term)| {
+                if position == self.unknown as usize {
+                    String::from("?")
+                } else if position == 2 {
+                    term.to_string()
+                } else {
+                    format_operand(*term)
+                }
+            })
+            .collect::<Vec<_>>();
+        if self.unknown == 2 {
+            writeln!(f, "Can you subtract these two numbers for me.")?;
+        } else {
+            writeln!(f, "Can you find the missing number for me.")?;
+        }
+        write!(f, "{} – {} = {}", terms[0], terms[1], terms[2])
     }
 }
 
@@ -82,7 +185,7 @@ impl Question for Difference {
     fn answer(&self, answer: &str) -> Outcome {
         match parse(answer) {
             Ok(answer) => {
-                let expected = i32::try_from(self.lhs).unwrap() - i32::try_from(self.rhs).unwrap();
+                let expected = self.terms()[self.unknown as usize];
                 if answer == expected {
                     Outcome::Correct
                 } else {
@@ -100,12 +203,16 @@ fn parse(answer: &str) -> Result<i32, String> {
 
 pub mod presets {
     use super::{Config, Subtraction};
+    use crate::topic::regrouping::Regrouping;
+    use crate::topic::Unknown;
 
     pub fn subtraction_1() -> Subtraction {
         Config {
             min_val: 0,
             max_val: 10,
             allow_negative: false,
+            unknown: Unknown::Result,
+            borrows: Regrouping::Any,
         }
         .try_into()
         .expect("misconfigured module")
@@ -116,6 +223,8 @@ pub mod presets {
             min_val: 0,
             max_val: 9_999,
             allow_negative: false,
+            unknown: Unknown::Result,
+            borrows: Regrouping::Any,
         }
         .try_into()
         .expect("misconfigured module")
@@ -126,6 +235,32 @@ pub mod presets {
             min_val: 0,
             max_val: 99_999_999,
             allow_negative: true,
+            unknown: Unknown::Result,
+            borrows: Regrouping::Any,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn subtraction_4() -> Subtraction {
+        Config {
+            min_val: -20,
+            max_val: 21,
+            allow_negative: true,
+            unknown: Unknown::Any,
+            borrows: Regrouping::Any,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn subtraction_5() -> Subtraction {
+        Config {
+            min_val: 0,
+            max_val: 9_999,
+            allow_negative: false,
+            unknown: Unknown::Any,
+            borrows: Regrouping::Any,
         }
         .try_into()
         .expect("misconfigured module")
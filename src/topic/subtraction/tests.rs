@@ -1,5 +1,7 @@
 use crate::topic::subtraction::{presets, Config, Subtraction};
-use crate::topic::{Module, Outcome};
+use crate::topic::regrouping::Regrouping;
+use crate::topic::{Module, Outcome, Unknown};
+use tinyrand::StdRand;
 use tinyrand_alloc::Mock;
 
 #[test]
@@ -14,10 +16,12 @@ fn display_ask_answer_nonnegative() {
         min_val: 10,
         max_val: 30,
         allow_negative: false,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
     })
     .unwrap();
 
-    let rand_nums = vec![17, 11];
+    let rand_nums = [17, 11];
     let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
         if surrogate.state().next_lim_u128_invocations() == 0 {
             assert_eq!(20, lim);
@@ -51,6 +55,8 @@ fn question_with_zero_nonnegative() {
         min_val: 0,
         max_val: 30,
         allow_negative: false,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
     })
     .unwrap();
 
@@ -71,10 +77,12 @@ fn display_ask_answer_negative() {
         min_val: 10,
         max_val: 30,
         allow_negative: true,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
     })
     .unwrap();
 
-    let rand_nums = vec![17, 29];
+    let rand_nums = [17, 29];
     let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
         if surrogate.state().next_lim_u128_invocations() == 0 {
             assert_eq!(20, lim);
@@ -102,22 +110,228 @@ fn display_ask_answer_negative() {
     assert_eq!(Outcome::Correct, question.answer("-2"));
 }
 
+#[test]
+fn display_ask_answer_missing_operand() {
+    let module = Subtraction::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        allow_negative: false,
+        unknown: Unknown::Operand,
+        borrows: Regrouping::Any,
+    })
+    .unwrap();
+
+    for (position, expected_display, expected_answer) in
+        [(0, "? – 11 = 16", "27"), (1, "27 – ? = 16", "11")]
+    {
+        let rand_nums = [17, 11, position];
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            if surrogate.state().next_lim_u128_invocations() == 2 {
+                assert_eq!(2, lim);
+            }
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains("Can you find the missing number for me."), "{}", s);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Incorrect, question.answer("16"));
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn display_ask_answer_any_unknown_negative() {
+    let module = Subtraction::try_from(Config {
+        min_val: 10,
+        max_val: 30,
+        allow_negative: true,
+        unknown: Unknown::Any,
+        borrows: Regrouping::Any,
+    })
+    .unwrap();
+
+    let rand_nums = [17, 29, 0];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 2 {
+            assert_eq!(3, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("? – 29 = -2"), "{}", s);
+    assert_eq!(Outcome::Incorrect, question.answer("-2"));
+    assert_eq!(Outcome::Correct, question.answer("27"));
+}
+
+#[test]
+fn display_ask_answer_signed() {
+    let module = Subtraction::try_from(Config {
+        min_val: -20,
+        max_val: 21,
+        allow_negative: true,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
+    })
+    .unwrap();
+
+    for (rand_nums, expected_display, expected_answer) in [
+        ([25, 17], "5 – (-3) = ?", "8"),
+        ([12, 17], "(-8) – (-3) = ?", "-5"),
+        ([12, 25], "(-8) – 5 = ?", "-13"),
+    ] {
+        let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+            assert_eq!(41, lim);
+            rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+        });
+
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        assert!(s.contains(expected_display), "{}", s);
+        assert_eq!(Outcome::Correct, question.answer(expected_answer));
+    }
+}
+
+#[test]
+fn signed_nonnegative() {
+    let module = Subtraction::try_from(Config {
+        min_val: -20,
+        max_val: 21,
+        allow_negative: false,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
+    })
+    .unwrap();
+
+    let mut rand = StdRand::default();
+    let mut negative_operands = 0;
+    for _ in 0..100 {
+        let question = module.ask(&mut rand);
+        let s = format!("{}", question);
+        let equation = s.lines().last().unwrap();
+        let (lhs, rhs) = equation.trim_end_matches(" = ?").split_once(" – ").unwrap();
+        let parse = |operand: &str| {
+            operand
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .parse::<i32>()
+                .unwrap()
+        };
+        let (lhs, rhs) = (parse(lhs), parse(rhs));
+        assert!((-20..21).contains(&lhs), "{}", s);
+        assert!((-20..21).contains(&rhs), "{}", s);
+        assert!(lhs >= rhs, "{}", s);
+        if lhs < 0 || rhs < 0 {
+            negative_operands += 1;
+        }
+        assert_eq!(Outcome::Correct, question.answer(&(lhs - rhs).to_string()));
+    }
+    assert!(negative_operands > 0);
+}
+
+#[test]
+fn constrained_borrows() {
+    fn borrows(mut lhs: u32, mut rhs: u32) -> u32 {
+        if lhs < rhs {
+            std::mem::swap(&mut lhs, &mut rhs);
+        }
+        let (mut borrows, mut borrow) = (0, 0);
+        while lhs > 0 {
+            borrow = u32::from(lhs % 10 < rhs % 10 + borrow);
+            borrows += borrow;
+            lhs /= 10;
+            rhs /= 10;
+        }
+        borrows
+    }
+
+    let mut rand = StdRand::default();
+    for allow_negative in [false, true] {
+        for (regrouping, admits) in [
+            (Regrouping::Forbid, (|borrows| borrows == 0) as fn(u32) -> bool),
+            (Regrouping::Require, |borrows| borrows > 0),
+            (Regrouping::Exactly(1), |borrows| borrows == 1),
+        ] {
+            let module = Subtraction::try_from(Config {
+                min_val: 100,
+                max_val: 1_000,
+                allow_negative,
+                unknown: Unknown::Result,
+                borrows: regrouping,
+            })
+            .unwrap();
+
+            let mut negatives = 0;
+            for _ in 0..100 {
+                let question = module.ask(&mut rand);
+                let s = format!("{}", question);
+                let equation = s.lines().last().unwrap();
+                let (lhs, rhs) = equation.trim_end_matches(" = ?").split_once(" – ").unwrap();
+                let (lhs, rhs) = (lhs.parse::<u32>().unwrap(), rhs.parse::<u32>().unwrap());
+                assert!((100..1_000).contains(&lhs), "{}", s);
+                assert!(rhs < 1_000, "{}", s);
+                assert!(admits(borrows(lhs, rhs)), "{:?}: {}", regrouping, s);
+                if lhs < rhs {
+                    negatives += 1;
+                }
+                let difference = i64::from(lhs) - i64::from(rhs);
+                assert_eq!(Outcome::Correct, question.answer(&difference.to_string()));
+            }
+            assert_eq!(allow_negative, negatives > 0);
+        }
+    }
+}
+
 #[test]
 fn invalid_config() {
     let module = Subtraction::try_from(Config {
         min_val: 10,
         max_val: 10,
         allow_negative: false,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
     });
     assert_eq!("min_val must be less than max_val", module.err().unwrap());
 
     let module = Subtraction::try_from(Config {
-        min_val: 10,
-        max_val: (u32::MAX << 1) + 1,
+        min_val: -10,
+        max_val: i32::MAX - 5,
         allow_negative: false,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Any,
+    });
+    assert_eq!(
+        format!(
+            "the difference between min_val and max_val cannot exceed {}",
+            i32::MAX
+        ),
+        module.err().unwrap()
+    );
+
+    let module = Subtraction::try_from(Config {
+        min_val: 0,
+        max_val: 10,
+        allow_negative: true,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Require,
+    });
+    assert_eq!(
+        "no differences between min_val and max_val satisfy the borrows constraint",
+        module.err().unwrap()
+    );
+
+    let module = Subtraction::try_from(Config {
+        min_val: -10,
+        max_val: 100,
+        allow_negative: true,
+        unknown: Unknown::Result,
+        borrows: Regrouping::Require,
     });
     assert_eq!(
-        format!("max_val cannot exceed {}", u32::MAX << 1),
+        "borrows can only be constrained for non-negative numbers",
         module.err().unwrap()
     );
 }
@@ -127,4 +341,6 @@ fn presets() {
     presets::subtraction_1();
     presets::subtraction_2();
     presets::subtraction_3();
+    presets::subtraction_4();
+    presets::subtraction_5();
 }
@@ -1,8 +1,28 @@
-use super::Outcome;
+use super::{choose, gcd, Outcome};
+use tinyrand_alloc::Mock;
 
 #[test]
 fn outcome_implements_debug() {
     let s = format!("{:?}", Outcome::Invalid(String::from("foo")));
     assert!(s.contains("Invalid"));
     assert!(s.contains("foo"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn choose_without_needless_draws() {
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(3, lim);
+        2
+    });
+    assert_eq!(&'a', choose(&['a'], &mut rand));
+    assert_eq!(&'c', choose(&['a', 'b', 'c'], &mut rand));
+    assert_eq!(1, rand.state().next_lim_u128_invocations());
+}
+
+#[test]
+fn gcd_of_pairs() {
+    assert_eq!(6_u32, gcd(12, 18));
+    assert_eq!(1_u32, gcd(7, 20));
+    assert_eq!(5_u64, gcd(5, 0));
+    assert_eq!(5_u64, gcd(0, 5));
+}
@@ -0,0 +1,427 @@
+//! Questions on telling the time and on elapsed time.
+
+use crate::topic::{choose, Module, Outcome, Question};
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tinyrand::RandRange;
+
+/// The time module.
+pub struct Time {
+    config: Config,
+}
+
+/// The kinds of exercises that may be asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exercise {
+    /// Reading the time off an analogue clock.
+    Analogue,
+
+    /// Reading a time off a 24-hour digital clock and giving it on a 12-hour clock.
+    Digital,
+
+    /// Working out the time elapsed between two times on the same day.
+    Elapsed,
+}
+
+/// Configuration for [`Time`].
+pub struct Config {
+    /// The times that will be asked are multiples of this many minutes. Must divide an hour.
+    pub step: u32,
+
+    /// The longest elapsed time that will be asked, in minutes.
+    pub max_elapsed: u32,
+
+    /// The exercises to choose from.
+    pub exercises: Vec<Exercise>,
+}
+
+const MINUTES_PER_HOUR: u32 = 60;
+const MINUTES_PER_HALF_DAY: u32 = 12 * MINUTES_PER_HOUR;
+const MINUTES_PER_DAY: u32 = 24 * MINUTES_PER_HOUR;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.step == 0 || !MINUTES_PER_HOUR.is_multiple_of(self.step) {
+            return Err(format!("step must be a divisor of {MINUTES_PER_HOUR}"));
+        }
+        if self.max_elapsed < self.step {
+            return Err("max_elapsed must be at least step".into());
+        }
+        if self.max_elapsed > MINUTES_PER_HALF_DAY {
+            return Err(format!("max_elapsed cannot exceed {MINUTES_PER_HALF_DAY}"));
+        }
+        if self.exercises.is_empty() {
+            return Err("at least one exercise must be specified".into());
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for Time {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+}
+
+impl Module for Time {
+    fn topic_name(&self) -> String {
+        String::from("time")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let step = self.config.step;
+        let exercise = *choose(&self.config.exercises, rand);
+        match exercise {
+            Exercise::Analogue => Box::new(Analogue {
+                time: rand.next_range(0..MINUTES_PER_HALF_DAY / step) * step,
+            }),
+            Exercise::Digital => Box::new(Digital {
+                time: rand.next_range(0..MINUTES_PER_DAY / step) * step,
+            }),
+            Exercise::Elapsed => {
+                let elapsed = rand.next_range(1..self.config.max_elapsed / step + 1) * step;
+                let start = rand.next_range(0..(MINUTES_PER_DAY - elapsed) / step) * step;
+                Box::new(Elapsed {
+                    start,
+                    end: start + elapsed,
+                })
+            }
+        }
+    }
+}
+
+/// Formats a time, given in minutes since midnight, as `H:MM`.
+fn format_time(time: u32) -> String {
+    format!("{}:{:02}", time / MINUTES_PER_HOUR, time % MINUTES_PER_HOUR)
+}
+
+/// Formats a time, given in minutes since midnight, as `HH:MM`, as shown on a 24-hour clock.
+fn format_digital(time: u32) -> String {
+    format!("{:02}:{:02}", time / MINUTES_PER_HOUR, time % MINUTES_PER_HOUR)
+}
+
+/// Draws an analogue clock showing the given time, in minutes since midnight.
+fn draw_clock(time: u32) -> String {
+    const RADIUS: u32 = 6;
+    const HOUR_HAND: f64 = 3.0;
+    const MINUTE_HAND: f64 = 5.0;
+    let radius = RADIUS as usize;
+    let mut grid = vec![vec![' '; 4 * radius + 1]; 2 * radius + 1];
+
+    // Columns are narrower than rows, so horizontal offsets are doubled to keep the face round.
+    // Hands and numerals never reach past the face, so the offsets stay within the grid.
+    let position = |angle: f64, length: f64| {
+        let dx = (2.0 * length * angle.sin()).round() as isize;
+        let dy = -(length * angle.cos()).round() as isize;
+        (
+            (2 * radius).checked_add_signed(dx).unwrap(),
+            radius.checked_add_signed(dy).unwrap(),
+        )
+    };
+    for numeral in 1..=12 {
+        let (x, y) = position(f64::from(numeral) * PI / 6.0, f64::from(RADIUS));
+        let digits = numeral.to_string();
+        let x = x + 1 - digits.len();
+        for (offset, digit) in digits.chars().enumerate() {
+            grid[y][x + offset] = digit;
+        }
+    }
+    let minutes = f64::from(time % MINUTES_PER_HOUR);
+    let hours = f64::from(time % MINUTES_PER_HALF_DAY) / f64::from(MINUTES_PER_HOUR);
+
+    // The hour hand is drawn last, so that it stays visible where the hands overlap.
+    for (angle, length, symbol) in [
+        (minutes * PI / 30.0, MINUTE_HAND, '*'),
+        (hours * PI / 6.0, HOUR_HAND, '#'),
+    ] {
+        let mut extent = 1.0;
+        while extent <= length {
+            let (x, y) = position(angle, extent);
+            grid[y][x] = symbol;
+            extent += 0.5;
+        }
+    }
+    grid[radius][2 * radius] = 'o';
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>().trim_end().to_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reading the time off an analogue clock.
+struct Analogue {
+    time: u32,
+}
+
+impl Display for Analogue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you tell me the time on this clock.")?;
+        writeln!(
+            f,
+            "The short hand (#) shows the hour; the long hand (*) shows the minutes."
+        )?;
+        write!(f, "{}", draw_clock(self.time))
+    }
+}
+
+impl Question for Analogue {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_time(answer) {
+            Ok((time, _)) => {
+                if time % MINUTES_PER_HALF_DAY == self.time % MINUTES_PER_HALF_DAY {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Reading a time off a 24-hour digital clock, and giving it on a 12-hour clock.
+struct Digital {
+    time: u32,
+}
+
+impl Display for Digital {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you tell me this time on a 12-hour clock.")?;
+        write!(f, "{}", format_digital(self.time))
+    }
+}
+
+impl Question for Digital {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_time(answer) {
+            Ok((_, Clock::TwentyFourHour)) => Outcome::Invalid(format!(
+                "'{answer}' is not a time on a 12-hour clock; write it as, e.g., '3:15' or 'quarter past 3'"
+            )),
+            Ok((time, Clock::Meridiem)) => {
+                if time == self.time {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Ok((time, Clock::TwelveHour)) => {
+                if time % MINUTES_PER_HALF_DAY == self.time % MINUTES_PER_HALF_DAY {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// Working out the time elapsed between two times.
+struct Elapsed {
+    start: u32,
+    end: u32,
+}
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you work out how much time has passed for me.")?;
+        write!(
+            f,
+            "From {} to {} is how long?",
+            format_time(self.start),
+            format_time(self.end)
+        )
+    }
+}
+
+impl Question for Elapsed {
+    fn answer(&self, answer: &str) -> Outcome {
+        match parse_duration(answer) {
+            Ok(duration) => {
+                if duration == self.end - self.start {
+                    Outcome::Correct
+                } else {
+                    Outcome::Incorrect
+                }
+            }
+            Err(err) => Outcome::Invalid(err),
+        }
+    }
+}
+
+/// How a time of day was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Clock {
+    /// On a 24-hour clock; e.g., `15:15`.
+    TwentyFourHour,
+
+    /// On a 12-hour clock, without saying whether it is before or after noon; e.g., `3:15`.
+    TwelveHour,
+
+    /// On a 12-hour clock, followed by `am` or `pm`; e.g., `3:15 pm`.
+    Meridiem,
+}
+
+/// Parses a time of day, returning the number of minutes since midnight alongside the
+/// [`Clock`] it was written on. Accepts digital times on a 12- or 24-hour clock (`3:15`,
+/// `15:15`, `3:15 pm`) and times in words (`3 o'clock`, `quarter past 3`, `half past 3`,
+/// `20 to 4`), optionally followed by `am` or `pm`. Times on a 12-hour clock without `am` or
+/// `pm` are taken to be before noon.
+pub(crate) fn parse_time(answer: &str) -> Result<(u32, Clock), String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid time; \
+            write it as, e.g., '3:15', '15:15' or 'quarter past 3'"
+        )
+    };
+    let normalised = answer.trim().to_lowercase().replace('.', "");
+    let words = normalised.split_whitespace().collect::<Vec<_>>();
+    let (words, meridiem) = match words.split_last() {
+        Some((&"am", words)) => (words, Some(0)),
+        Some((&"pm", words)) => (words, Some(12)),
+        _ => (words.as_slice(), None),
+    };
+    let hour_of = |s: &str| match u32::from_str(s) {
+        Ok(hour @ 1..=12) => Ok(hour % 12),
+        _ => Err(err()),
+    };
+    let (hour, minute, twelve_hour) = match words {
+        [hour] if meridiem.is_some() && !hour.contains(':') => (hour_of(hour)?, 0, true),
+        [time] => {
+            let (hour, minute) = time.split_once(':').ok_or_else(err)?;
+            let hour = u32::from_str(hour).map_err(|_| err())?;
+            if minute.len() != 2 {
+                return Err(err());
+            }
+            let minute = u32::from_str(minute).map_err(|_| err())?;
+            if hour >= 24 || minute >= MINUTES_PER_HOUR {
+                return Err(err());
+            }
+            let twelve_hour = (1..=12).contains(&hour);
+            (
+                if twelve_hour { hour % 12 } else { hour },
+                minute,
+                twelve_hour,
+            )
+        }
+        [hour, "o'clock" | "oclock"] => (hour_of(hour)?, 0, true),
+        [minutes @ .., relation @ ("past" | "to"), hour] => {
+            let minutes = match minutes {
+                ["quarter"] | ["a", "quarter"] => 15,
+                ["half"] if *relation == "past" => 30,
+                [minutes] | [minutes, "minute" | "minutes" | "min" | "mins"] => {
+                    match u32::from_str(minutes) {
+                        Ok(minutes @ 1..=30) => minutes,
+                        _ => return Err(err()),
+                    }
+                }
+                _ => return Err(err()),
+            };
+            let hour = hour_of(hour)?;
+            if *relation == "past" {
+                (hour, minutes, true)
+            } else {
+                ((hour + 11) % 12, MINUTES_PER_HOUR - minutes, true)
+            }
+        }
+        _ => return Err(err()),
+    };
+    match meridiem {
+        None if twelve_hour => Ok((hour * MINUTES_PER_HOUR + minute, Clock::TwelveHour)),
+        None => Ok((hour * MINUTES_PER_HOUR + minute, Clock::TwentyFourHour)),
+        Some(_) if !twelve_hour => Err(err()),
+        Some(offset) => Ok(((hour + offset) * MINUTES_PER_HOUR + minute, Clock::Meridiem)),
+    }
+}
+
+/// Parses a duration, returning the number of minutes. Accepts hours and minutes (`1:35`),
+/// minutes (`95`), and durations with units (`1 hour 35 minutes`, `1h 35m`).
+fn parse_duration(answer: &str) -> Result<u32, String> {
+    let err = || {
+        format!(
+            "'{answer}' does not appear to be a valid duration; \
+            write it as, e.g., '1:35', '95' or '1 hour 35 minutes'"
+        )
+    };
+    let normalised = answer.trim().to_lowercase();
+    if let Some((hours, minutes)) = normalised.split_once(':') {
+        let hours = u32::from_str(hours).map_err(|_| err())?;
+        if minutes.len() != 2 {
+            return Err(err());
+        }
+        let minutes = u32::from_str(minutes).map_err(|_| err())?;
+        if minutes >= MINUTES_PER_HOUR {
+            return Err(err());
+        }
+        return hours
+            .checked_mul(MINUTES_PER_HOUR)
+            .and_then(|hours| hours.checked_add(minutes))
+            .ok_or_else(err);
+    }
+    if let Ok(minutes) = u32::from_str(&normalised) {
+        return Ok(minutes);
+    }
+
+    // Separate numbers from their units, so that '1h' is read the same as '1 h'.
+    let mut spaced = String::new();
+    for ch in normalised.chars() {
+        if ch.is_ascii_alphabetic() && spaced.ends_with(|prev: char| prev.is_ascii_digit()) {
+            spaced.push(' ');
+        }
+        spaced.push(ch);
+    }
+    let words = spaced.split_whitespace().collect::<Vec<_>>();
+    if words.is_empty() || !words.len().is_multiple_of(2) {
+        return Err(err());
+    }
+    let mut total = 0_u32;
+    for pair in words.chunks(2) {
+        let amount = u32::from_str(pair[0]).map_err(|_| err())?;
+        let scale = match pair[1] {
+            "h" | "hr" | "hrs" | "hour" | "hours" => MINUTES_PER_HOUR,
+            "m" | "min" | "mins" | "minute" | "minutes" => 1,
+            _ => return Err(err()),
+        };
+        total = amount
+            .checked_mul(scale)
+            .and_then(|minutes| total.checked_add(minutes))
+            .ok_or_else(err)?;
+    }
+    Ok(total)
+}
+
+pub mod presets {
+    use super::{Config, Exercise, Time};
+
+    pub fn time_1() -> Time {
+        Config {
+            step: 15,
+            max_elapsed: 60,
+            exercises: vec![Exercise::Analogue],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn time_2() -> Time {
+        Config {
+            step: 5,
+            max_elapsed: 180,
+            exercises: vec![Exercise::Analogue, Exercise::Digital, Exercise::Elapsed],
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,249 @@
+use crate::topic::time::{parse_time, presets, Clock, Config, Exercise, Time};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::time_1();
+    assert_eq!("time", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_analogue() {
+    let module = Time::try_from(Config {
+        step: 15,
+        max_elapsed: 60,
+        exercises: vec![Exercise::Analogue],
+    })
+    .unwrap();
+
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(48, lim);
+        12
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you tell me the time on this clock."),
+        "{}",
+        s
+    );
+    let clock = [
+        "           12",
+        "     11     *     1",
+        "            *",
+        " 10         *         2",
+        "            *",
+        "            *",
+        "9           o #####     3",
+        "",
+        "",
+        "  8                   4",
+        "",
+        "      7           5",
+        "            6",
+    ]
+    .join("\n");
+    assert!(s.ends_with(&clock), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'foo' does not appear to be a valid time; write it as, e.g., '3:15', '15:15' or 'quarter past 3'"
+                .into()
+        ),
+        question.answer("foo")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("12:15"));
+    assert_eq!(Outcome::Incorrect, question.answer("quarter past 3"));
+    assert_eq!(Outcome::Correct, question.answer("3:00"));
+    assert_eq!(Outcome::Correct, question.answer("03:00"));
+    assert_eq!(Outcome::Correct, question.answer("15:00"));
+    assert_eq!(Outcome::Correct, question.answer("3 o'clock"));
+    assert_eq!(Outcome::Correct, question.answer("3 pm"));
+}
+
+#[test]
+fn display_ask_answer_digital() {
+    let module = Time::try_from(Config {
+        step: 15,
+        max_elapsed: 60,
+        exercises: vec![Exercise::Analogue, Exercise::Digital],
+    })
+    .unwrap();
+
+    let rand_nums = [1, 63];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 1 {
+            assert_eq!(96, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you tell me this time on a 12-hour clock."),
+        "{}",
+        s
+    );
+    assert!(s.ends_with("15:45"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'15:45' is not a time on a 12-hour clock; write it as, e.g., '3:15' or 'quarter past 3'"
+                .into()
+        ),
+        question.answer("15:45")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("4:45"));
+    assert_eq!(Outcome::Incorrect, question.answer("quarter past 4"));
+    assert_eq!(Outcome::Correct, question.answer("3:45"));
+    assert_eq!(Outcome::Incorrect, question.answer("3:45 am"));
+    assert_eq!(Outcome::Incorrect, question.answer("quarter to 4 am"));
+    assert_eq!(Outcome::Correct, question.answer("3:45 pm"));
+    assert_eq!(Outcome::Correct, question.answer("quarter to 4 pm"));
+    assert_eq!(Outcome::Correct, question.answer("Quarter to 4"));
+    assert_eq!(Outcome::Correct, question.answer("15 minutes to 4"));
+
+    let rand_nums = [1, 13];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.ends_with("03:15"), "{}", s);
+    assert_eq!(Outcome::Correct, question.answer("3:15"));
+    assert_eq!(Outcome::Correct, question.answer("3:15 am"));
+    assert_eq!(Outcome::Incorrect, question.answer("3:15 pm"));
+}
+
+#[test]
+fn display_ask_answer_elapsed() {
+    let module = Time::try_from(Config {
+        step: 5,
+        max_elapsed: 180,
+        exercises: vec![Exercise::Elapsed],
+    })
+    .unwrap();
+
+    let rand_nums = [18, 116];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(36, lim),
+            _ => assert_eq!(269, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(
+        s.contains("Can you work out how much time has passed for me."),
+        "{}",
+        s
+    );
+    assert!(s.contains("From 9:40 to 11:15 is how long?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'an hour' does not appear to be a valid duration; write it as, e.g., '1:35', '95' or '1 hour 35 minutes'"
+                .into()
+        ),
+        question.answer("an hour")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("1:25"));
+    assert_eq!(Outcome::Incorrect, question.answer("135"));
+    for answer in [
+        "1:35",
+        "95",
+        "95 minutes",
+        "1 hour 35 minutes",
+        "1h 35m",
+        "1 hr 35 mins",
+    ] {
+        assert_eq!(Outcome::Correct, question.answer(answer), "{answer}");
+    }
+}
+
+#[test]
+fn parse_time_forms() {
+    for (answer, expected) in [
+        ("0:05", (5, Clock::TwentyFourHour)),
+        ("12:30", (30, Clock::TwelveHour)),
+        ("12:30 am", (30, Clock::Meridiem)),
+        ("12:30 pm", (750, Clock::Meridiem)),
+        ("23:59", (1_439, Clock::TwentyFourHour)),
+        ("9:05 a.m.", (545, Clock::Meridiem)),
+        ("12 o'clock", (0, Clock::TwelveHour)),
+        ("half past 12", (30, Clock::TwelveHour)),
+        ("a quarter to 1", (45, Clock::TwelveHour)),
+        ("20 past 7", (440, Clock::TwelveHour)),
+        ("5 to 12", (715, Clock::TwelveHour)),
+        ("quarter to 7 pm", (1_125, Clock::Meridiem)),
+    ] {
+        assert_eq!(Ok(expected), parse_time(answer), "{answer}");
+    }
+    for answer in [
+        "",
+        "3",
+        "24:00",
+        "3:60",
+        "3:5",
+        "13 o'clock",
+        "half to 4",
+        "31 past 3",
+        "15:00 pm",
+        "quarter past",
+    ] {
+        assert!(parse_time(answer).is_err(), "{answer}");
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = Time::try_from(Config {
+        step: 7,
+        max_elapsed: 60,
+        exercises: vec![Exercise::Analogue],
+    });
+    assert_eq!("step must be a divisor of 60", module.err().unwrap());
+
+    let module = Time::try_from(Config {
+        step: 0,
+        max_elapsed: 60,
+        exercises: vec![Exercise::Analogue],
+    });
+    assert_eq!("step must be a divisor of 60", module.err().unwrap());
+
+    let module = Time::try_from(Config {
+        step: 15,
+        max_elapsed: 10,
+        exercises: vec![Exercise::Analogue],
+    });
+    assert_eq!("max_elapsed must be at least step", module.err().unwrap());
+
+    let module = Time::try_from(Config {
+        step: 15,
+        max_elapsed: 735,
+        exercises: vec![Exercise::Analogue],
+    });
+    assert_eq!("max_elapsed cannot exceed 720", module.err().unwrap());
+
+    let module = Time::try_from(Config {
+        step: 15,
+        max_elapsed: 60,
+        exercises: vec![],
+    });
+    assert_eq!(
+        "at least one exercise must be specified",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::time_1();
+    presets::time_2();
+}
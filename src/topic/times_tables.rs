@@ -0,0 +1,147 @@
+//! Drills on the times tables.
+
+use crate::topic::multiplication::Product;
+use crate::topic::{choose, Module, Question};
+use std::cell::RefCell;
+use tinyrand::RandRange;
+
+/// The times tables module. Unlike [`Multiplication`](crate::topic::multiplication::Multiplication),
+/// which picks both operands uniformly, questions are only drawn from a chosen set of tables.
+pub struct TimesTables {
+    config: Config,
+
+    /// Facts that have yet to be asked in the current round. Only used when
+    /// [`Config::cover_all`] is set.
+    remaining: RefCell<Vec<Product>>,
+}
+
+/// Configuration for [`TimesTables`].
+pub struct Config {
+    /// The tables to drill; e.g., `vec![3, 7, 12]` for the 3s, 7s and 12s.
+    pub tables: Vec<u32>,
+
+    /// The smallest multiplier that will be asked.
+    pub min_multiplier: u32,
+
+    /// The largest multiplier that will be asked.
+    pub max_multiplier: u32,
+
+    /// Ask every fact in the chosen tables once before any fact is repeated.
+    pub cover_all: bool,
+}
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        const MAX_FACTS: u64 = 1_000;
+        if self.tables.is_empty() {
+            return Err("at least one table must be specified".into());
+        }
+        for (index, table) in self.tables.iter().enumerate() {
+            if self.tables[..index].contains(table) {
+                return Err(format!("table {table} is specified more than once"));
+            }
+        }
+        if self.min_multiplier >= self.max_multiplier {
+            return Err("min_multiplier must be less than max_multiplier".into());
+        }
+        let max_table = self.tables.iter().max().unwrap();
+        if max_table.checked_mul(self.max_multiplier).is_none() {
+            return Err(format!(
+                "the product of the largest table and max_multiplier cannot exceed {}",
+                u32::MAX
+            ));
+        }
+
+        // Every fact is listed up front when covering them all.
+        let facts = self.tables.len() as u64 * u64::from(self.max_multiplier - self.min_multiplier);
+        if self.cover_all && facts > MAX_FACTS {
+            return Err(format!(
+                "cover_all cannot be used with more than {MAX_FACTS} facts"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Enumerates all facts covered by this config.
+    fn facts(&self) -> Vec<Product> {
+        self.tables
+            .iter()
+            .flat_map(|&table| {
+                (self.min_multiplier..self.max_multiplier).map(move |multiplier| Product {
+                    lhs: multiplier,
+                    rhs: table,
+                })
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Config> for TimesTables {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            remaining: RefCell::new(vec![]),
+        })
+    }
+}
+
+impl Module for TimesTables {
+    fn topic_name(&self) -> String {
+        String::from("times tables")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        if self.config.cover_all {
+            let mut remaining = self.remaining.borrow_mut();
+            if remaining.is_empty() {
+                *remaining = self.config.facts();
+            }
+            let index = rand.next_range(0..u32::try_from(remaining.len()).unwrap());
+            Box::new(remaining.swap_remove(index as usize))
+        } else {
+            let table = *choose(&self.config.tables, rand);
+            let multiplier =
+                rand.next_range(self.config.min_multiplier..self.config.max_multiplier);
+            Box::new(Product {
+                lhs: multiplier,
+                rhs: table,
+            })
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, TimesTables};
+
+    pub fn times_tables_1() -> TimesTables {
+        Config {
+            tables: vec![2, 5, 10],
+            min_multiplier: 1,
+            max_multiplier: 11,
+            cover_all: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn times_tables_2() -> TimesTables {
+        Config {
+            tables: (2..=12).collect(),
+            min_multiplier: 1,
+            max_multiplier: 13,
+            cover_all: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
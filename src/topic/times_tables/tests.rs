@@ -0,0 +1,143 @@
+use crate::topic::times_tables::{presets, Config, TimesTables};
+use crate::topic::{Module, Outcome};
+use std::collections::HashSet;
+use tinyrand::StdRand;
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::times_tables_1();
+    assert_eq!("times tables", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer() {
+    let module = TimesTables::try_from(Config {
+        tables: vec![3, 7, 12],
+        min_multiplier: 1,
+        max_multiplier: 13,
+        cover_all: false,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 5];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        if surrogate.state().next_lim_u128_invocations() == 0 {
+            assert_eq!(3, lim);
+        } else {
+            assert_eq!(12, lim);
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("6 × 7"), "{}", s);
+
+    assert!(matches!(question.answer("foo"), Outcome::Invalid(_)));
+    assert_eq!(Outcome::Incorrect, question.answer("43"));
+    assert_eq!(Outcome::Correct, question.answer("42"));
+}
+
+#[test]
+fn cover_all_before_repeating() {
+    let module = TimesTables::try_from(Config {
+        tables: vec![3, 7, 12],
+        min_multiplier: 1,
+        max_multiplier: 11,
+        cover_all: true,
+    })
+    .unwrap();
+
+    let mut rand = StdRand::default();
+    for _ in 0..3 {
+        let questions = (0..30)
+            .map(|_| format!("{}", module.ask(&mut rand)))
+            .collect::<HashSet<_>>();
+        assert_eq!(30, questions.len());
+        for table in [3, 7, 12] {
+            for multiplier in 1..11 {
+                let fact = format!("{multiplier} × {table} = ?");
+                assert!(
+                    questions.iter().any(|question| question.ends_with(&fact)),
+                    "missing {fact}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let module = TimesTables::try_from(Config {
+        tables: vec![],
+        min_multiplier: 1,
+        max_multiplier: 13,
+        cover_all: false,
+    });
+    assert_eq!(
+        "at least one table must be specified",
+        module.err().unwrap()
+    );
+
+    let module = TimesTables::try_from(Config {
+        tables: vec![3, 7, 3],
+        min_multiplier: 1,
+        max_multiplier: 13,
+        cover_all: false,
+    });
+    assert_eq!("table 3 is specified more than once", module.err().unwrap());
+
+    let module = TimesTables::try_from(Config {
+        tables: vec![3],
+        min_multiplier: 13,
+        max_multiplier: 13,
+        cover_all: false,
+    });
+    assert_eq!(
+        "min_multiplier must be less than max_multiplier",
+        module.err().unwrap()
+    );
+
+    let module = TimesTables::try_from(Config {
+        tables: vec![3, 1 << 16],
+        min_multiplier: 1,
+        max_multiplier: 1 << 16,
+        cover_all: false,
+    });
+    assert_eq!(
+        format!(
+            "the product of the largest table and max_multiplier cannot exceed {}",
+            u32::MAX
+        ),
+        module.err().unwrap()
+    );
+
+    let module = TimesTables::try_from(Config {
+        tables: vec![1],
+        min_multiplier: 0,
+        max_multiplier: u32::MAX,
+        cover_all: true,
+    });
+    assert_eq!(
+        "cover_all cannot be used with more than 1000 facts",
+        module.err().unwrap()
+    );
+
+    let module = TimesTables::try_from(Config {
+        tables: vec![2, 3],
+        min_multiplier: 0,
+        max_multiplier: 501,
+        cover_all: true,
+    });
+    assert_eq!(
+        "cover_all cannot be used with more than 1000 facts",
+        module.err().unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    presets::times_tables_1();
+    presets::times_tables_2();
+}
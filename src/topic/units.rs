@@ -0,0 +1,451 @@
+//! Questions on converting between units of measurement.
+
+use crate::topic::{choose, decimals, Module, Outcome, Question};
+use std::fmt::{Display, Formatter};
+use tinyrand::RandRange;
+
+/// The units module.
+pub struct Units {
+    config: Config,
+
+    /// The pairs of units that may be converted between, grouped by family, with the larger unit
+    /// first.
+    pairs: Vec<Vec<(&'static Unit, &'static Unit)>>,
+}
+
+/// A family of units that measure the same quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Family {
+    Length,
+    Mass,
+    Volume,
+    Time,
+}
+
+/// A system of units. Conversions are only asked between units of the same system. Units of
+/// time belong to every system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum System {
+    Metric,
+    Imperial,
+}
+
+/// Configuration for [`Units`].
+pub struct Config {
+    /// The unit families to choose from.
+    pub families: Vec<Family>,
+
+    /// The systems of units to choose from.
+    pub systems: Vec<System>,
+
+    /// The largest quantity (in the larger of the two units) that will be asked.
+    pub max_val: u32,
+
+    /// Allow quantities of a half unit; e.g., `2.5 litres`. Halves are only asked where the
+    /// converted quantity is whole.
+    pub halves: bool,
+}
+
+/// The largest ratio between a pair of units that will be asked.
+const MAX_RATIO: u64 = 10_000;
+
+impl Config {
+    /// Validates the given config.
+    ///
+    /// # Errors
+    /// If the config is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_and_pair().map(|_| ())
+    }
+
+    /// Validates the config, returning the pairs of units that may be converted between.
+    fn validate_and_pair(&self) -> Result<Vec<Vec<(&'static Unit, &'static Unit)>>, String> {
+        const MAX_MAX_VAL: u32 = 10_000;
+        if self.families.is_empty() {
+            return Err("at least one unit family must be specified".into());
+        }
+        if self.systems.is_empty() {
+            return Err("at least one system must be specified".into());
+        }
+        if self.max_val < 2 {
+            return Err("max_val must be at least 2".into());
+        }
+        if self.max_val > MAX_MAX_VAL {
+            return Err(format!("max_val cannot exceed {MAX_MAX_VAL}"));
+        }
+        Ok(self
+            .families
+            .iter()
+            .map(|&family| {
+                let units = UNITS
+                    .iter()
+                    .filter(|unit| {
+                        unit.family == family
+                            && unit
+                                .system
+                                .is_none_or(|system| self.systems.contains(&system))
+                    })
+                    .collect::<Vec<_>>();
+                let mut pairs = vec![];
+                for &larger in &units {
+                    for &smaller in &units {
+                        if larger.system == smaller.system
+                            && larger.size > smaller.size
+                            && larger.size / smaller.size <= MAX_RATIO
+                        {
+                            pairs.push((larger, smaller));
+                        }
+                    }
+                }
+                pairs
+            })
+            .collect())
+    }
+}
+
+impl TryFrom<Config> for Units {
+    type Error = String;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        let pairs = config.validate_and_pair()?;
+        Ok(Self { config, pairs })
+    }
+}
+
+impl Module for Units {
+    fn topic_name(&self) -> String {
+        String::from("units")
+    }
+
+    fn ask(&self, rand: &mut dyn RandRange<u32>) -> Box<dyn Question> {
+        let pairs = choose(&self.pairs, rand);
+        let (larger, smaller) = *choose(pairs, rand);
+        let ratio = larger.size / smaller.size;
+        let upward = rand.next_range(0..2) == 1;
+        let halves = if self.config.halves && ratio.is_multiple_of(2) {
+            rand.next_range(2..self.config.max_val * 2)
+        } else {
+            rand.next_range(1..self.config.max_val) * 2
+        };
+        let larger_halves = u64::from(halves);
+        let smaller_halves = larger_halves * ratio;
+        Box::new(if upward {
+            Conversion {
+                from: smaller,
+                to: larger,
+                halves: smaller_halves,
+                expected_halves: larger_halves,
+            }
+        } else {
+            Conversion {
+                from: larger,
+                to: smaller,
+                halves: larger_halves,
+                expected_halves: smaller_halves,
+            }
+        })
+    }
+}
+
+/// A unit of measurement.
+struct Unit {
+    family: Family,
+
+    /// The system that the unit belongs to, or `None` if it belongs to every system.
+    system: Option<System>,
+    singular: &'static str,
+    plural: &'static str,
+
+    /// Alternative names, spellings and symbols that are accepted in answers.
+    aliases: &'static [&'static str],
+
+    /// The size of the unit, as a multiple of the smallest unit in its family and system.
+    size: u64,
+}
+
+impl Unit {
+    fn name(&self, halves: u64) -> &'static str {
+        if halves == 2 {
+            self.singular
+        } else {
+            self.plural
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.singular == name || self.plural == name || self.aliases.contains(&name)
+    }
+}
+
+const fn unit(
+    family: Family,
+    system: Option<System>,
+    singular: &'static str,
+    plural: &'static str,
+    aliases: &'static [&'static str],
+    size: u64,
+) -> Unit {
+    Unit {
+        family,
+        system,
+        singular,
+        plural,
+        aliases,
+        size,
+    }
+}
+
+const METRIC: Option<System> = Some(System::Metric);
+const IMPERIAL: Option<System> = Some(System::Imperial);
+
+static UNITS: [Unit; 24] = [
+    unit(
+        Family::Length,
+        METRIC,
+        "millimetre",
+        "millimetres",
+        &["mm", "millimeter", "millimeters"],
+        1,
+    ),
+    unit(
+        Family::Length,
+        METRIC,
+        "centimetre",
+        "centimetres",
+        &["cm", "centimeter", "centimeters"],
+        10,
+    ),
+    unit(
+        Family::Length,
+        METRIC,
+        "metre",
+        "metres",
+        &["m", "meter", "meters"],
+        1_000,
+    ),
+    unit(
+        Family::Length,
+        METRIC,
+        "kilometre",
+        "kilometres",
+        &["km", "kilometer", "kilometers"],
+        1_000_000,
+    ),
+    unit(Family::Length, IMPERIAL, "inch", "inches", &["in", "\""], 1),
+    unit(Family::Length, IMPERIAL, "foot", "feet", &["ft", "'"], 12),
+    unit(
+        Family::Length,
+        IMPERIAL,
+        "yard",
+        "yards",
+        &["yd", "yds"],
+        36,
+    ),
+    unit(Family::Length, IMPERIAL, "mile", "miles", &["mi"], 63_360),
+    unit(Family::Mass, METRIC, "milligram", "milligrams", &["mg"], 1),
+    unit(Family::Mass, METRIC, "gram", "grams", &["g"], 1_000),
+    unit(
+        Family::Mass,
+        METRIC,
+        "kilogram",
+        "kilograms",
+        &["kg"],
+        1_000_000,
+    ),
+    unit(
+        Family::Mass,
+        METRIC,
+        "tonne",
+        "tonnes",
+        &["t"],
+        1_000_000_000,
+    ),
+    unit(Family::Mass, IMPERIAL, "ounce", "ounces", &["oz"], 1),
+    unit(
+        Family::Mass,
+        IMPERIAL,
+        "pound",
+        "pounds",
+        &["lb", "lbs"],
+        16,
+    ),
+    unit(
+        Family::Mass,
+        IMPERIAL,
+        "stone",
+        "stone",
+        &["st", "stones"],
+        224,
+    ),
+    unit(
+        Family::Volume,
+        METRIC,
+        "millilitre",
+        "millilitres",
+        &["ml", "milliliter", "milliliters"],
+        1,
+    ),
+    unit(
+        Family::Volume,
+        METRIC,
+        "litre",
+        "litres",
+        &["l", "liter", "liters"],
+        1_000,
+    ),
+    unit(
+        Family::Volume,
+        IMPERIAL,
+        "fluid ounce",
+        "fluid ounces",
+        &["fl oz"],
+        1,
+    ),
+    unit(Family::Volume, IMPERIAL, "pint", "pints", &["pt"], 20),
+    unit(Family::Volume, IMPERIAL, "gallon", "gallons", &["gal"], 160),
+    unit(
+        Family::Time,
+        None,
+        "second",
+        "seconds",
+        &["s", "sec", "secs"],
+        1,
+    ),
+    unit(
+        Family::Time,
+        None,
+        "minute",
+        "minutes",
+        &["min", "mins"],
+        60,
+    ),
+    unit(
+        Family::Time,
+        None,
+        "hour",
+        "hours",
+        &["h", "hr", "hrs"],
+        3_600,
+    ),
+    unit(Family::Time, None, "day", "days", &["d"], 86_400),
+];
+
+/// Formats a quantity given in halves; e.g., `5` as `2.5`.
+fn format_halves(halves: u64) -> String {
+    if halves.is_multiple_of(2) {
+        (halves / 2).to_string()
+    } else {
+        format!("{}.5", halves / 2)
+    }
+}
+
+/// Removes the thousands separators from a number; e.g., `2,500` becomes `2500`. Returns `None`
+/// if a comma is not followed by a group of exactly three digits, as in `2,5`, which may have been
+/// meant as a decimal comma.
+fn strip_separators(number: &str) -> Option<String> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.starts_with(',')
+        || fraction.contains(',')
+        || whole.split(',').skip(1).any(|group| group.len() != 3)
+    {
+        None
+    } else {
+        Some(number.replace(',', ""))
+    }
+}
+
+/// Converting a quantity from one unit to another.
+struct Conversion {
+    from: &'static Unit,
+    to: &'static Unit,
+
+    /// The quantity to convert, in halves of the `from` unit.
+    halves: u64,
+
+    /// The converted quantity, in halves of the `to` unit.
+    expected_halves: u64,
+}
+
+impl Display for Conversion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Can you convert this for me.")?;
+        write!(
+            f,
+            "How many {} in {} {}?",
+            self.to.plural,
+            format_halves(self.halves),
+            self.from.name(self.halves)
+        )
+    }
+}
+
+impl Question for Conversion {
+    fn answer(&self, answer: &str) -> Outcome {
+        let err = || {
+            format!(
+                "'{answer}' does not appear to be a valid quantity; write a number, optionally \
+                followed by the unit (e.g., '{} {}')",
+                format_halves(self.expected_halves),
+                self.to.name(self.expected_halves)
+            )
+        };
+        let trimmed = answer.trim();
+        let split = trimmed
+            .find(|ch: char| !ch.is_ascii_digit() && ch != '.' && ch != ',')
+            .unwrap_or(trimmed.len());
+        let (number, suffix) = trimmed.split_at(split);
+        let suffix = suffix.trim().to_lowercase();
+        if !suffix.is_empty() && !self.to.matches(&suffix) {
+            return match UNITS.iter().find(|unit| unit.matches(&suffix)) {
+                Some(unit) => Outcome::Invalid(format!(
+                    "'{answer}' is in {}, but the question asks for {}",
+                    unit.plural, self.to.plural
+                )),
+                None => Outcome::Invalid(err()),
+            };
+        }
+        let Some(number) = strip_separators(number) else {
+            return Outcome::Invalid(format!(
+                "'{answer}' uses a comma other than to separate thousands; \
+                write decimals with a point (e.g., '2.5')"
+            ));
+        };
+        let Ok(number) = decimals::parse_scaled(&number, 1) else {
+            return Outcome::Invalid(err());
+        };
+        if number == self.expected_halves * 5 {
+            Outcome::Correct
+        } else {
+            Outcome::Incorrect
+        }
+    }
+}
+
+pub mod presets {
+    use super::{Config, Family, System, Units};
+
+    pub fn units_1() -> Units {
+        Config {
+            families: vec![Family::Length, Family::Mass, Family::Volume],
+            systems: vec![System::Metric],
+            max_val: 10,
+            halves: false,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+
+    pub fn units_2() -> Units {
+        Config {
+            families: vec![Family::Length, Family::Mass, Family::Volume, Family::Time],
+            systems: vec![System::Metric, System::Imperial],
+            max_val: 20,
+            halves: true,
+        }
+        .try_into()
+        .expect("misconfigured module")
+    }
+}
+
+#[cfg(test)]
+mod tests;
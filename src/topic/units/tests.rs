@@ -0,0 +1,182 @@
+use crate::topic::units::{presets, Config, Family, System, Units};
+use crate::topic::{Module, Outcome};
+use tinyrand_alloc::Mock;
+
+#[test]
+fn name() {
+    let module = presets::units_1();
+    assert_eq!("units", module.topic_name());
+}
+
+#[test]
+fn display_ask_answer_downward() {
+    let module = Units::try_from(Config {
+        families: vec![Family::Volume],
+        systems: vec![System::Metric],
+        max_val: 10,
+        halves: true,
+    })
+    .unwrap();
+
+    let rand_nums = [0, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(2, lim),
+            _ => assert_eq!(18, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("Can you convert this for me."), "{}", s);
+    assert!(s.contains("How many millilitres in 2.5 litres?"), "{}", s);
+
+    assert_eq!(
+        Outcome::Invalid(
+            "'2500 cats' does not appear to be a valid quantity; write a number, optionally followed by the unit (e.g., '2500 millilitres')"
+                .into()
+        ),
+        question.answer("2500 cats")
+    );
+    assert_eq!(
+        Outcome::Invalid("'2.5 l' is in litres, but the question asks for millilitres".into()),
+        question.answer("2.5 l")
+    );
+    assert_eq!(Outcome::Incorrect, question.answer("250"));
+    assert_eq!(Outcome::Incorrect, question.answer("2499 ml"));
+    for answer in ["25,00", ",500", "2,500,0", "2,500.0,0"] {
+        assert!(
+            matches!(question.answer(answer), Outcome::Invalid(_)),
+            "{answer}"
+        );
+    }
+    for answer in [
+        "2500",
+        "2,500",
+        "2,500 ml",
+        "2500 ml",
+        "2500mL",
+        "2500 millilitres",
+        " 2500 Milliliters ",
+    ] {
+        assert_eq!(Outcome::Correct, question.answer(answer), "{answer}");
+    }
+}
+
+#[test]
+fn display_ask_answer_upward() {
+    let module = Units::try_from(Config {
+        families: vec![Family::Volume],
+        systems: vec![System::Metric],
+        max_val: 10,
+        halves: true,
+    })
+    .unwrap();
+
+    let rand_nums = [1, 3];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, _| {
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("How many litres in 2500 millilitres?"), "{}", s);
+
+    assert_eq!(Outcome::Incorrect, question.answer("2"));
+    assert_eq!(Outcome::Incorrect, question.answer("2.4"));
+    assert_eq!(
+        Outcome::Invalid(
+            "'2.51' does not appear to be a valid quantity; write a number, optionally followed by the unit (e.g., '2.5 litres')"
+                .into()
+        ),
+        question.answer("2.51")
+    );
+    assert_eq!(
+        Outcome::Invalid(
+            "'2,5 litres' uses a comma other than to separate thousands; write decimals with a point (e.g., '2.5')"
+                .into()
+        ),
+        question.answer("2,5 litres")
+    );
+    for answer in ["2.5", "2.50", "2.5 litres", "2.5L"] {
+        assert_eq!(Outcome::Correct, question.answer(answer), "{answer}");
+    }
+}
+
+#[test]
+fn display_ask_answer_imperial() {
+    let module = Units::try_from(Config {
+        families: vec![Family::Length, Family::Time],
+        systems: vec![System::Imperial],
+        max_val: 10,
+        halves: true,
+    })
+    .unwrap();
+
+    let rand_nums = [0, 2, 0, 0];
+    let mut rand = Mock::default().with_next_lim_u128(|surrogate, lim| {
+        match surrogate.state().next_lim_u128_invocations() {
+            0 => assert_eq!(2, lim),
+            1 => assert_eq!(5, lim),
+            2 => assert_eq!(2, lim),
+            _ => assert_eq!(9, lim),
+        }
+        rand_nums[surrogate.state().next_lim_u128_invocations() as usize]
+    });
+
+    let question = module.ask(&mut rand);
+    let s = format!("{}", question);
+    assert!(s.contains("How many feet in 1 yard?"), "{}", s);
+    assert_eq!(Outcome::Incorrect, question.answer("12"));
+    assert_eq!(Outcome::Correct, question.answer("3"));
+    assert_eq!(Outcome::Correct, question.answer("3 ft"));
+    assert_eq!(Outcome::Correct, question.answer("3 feet"));
+}
+
+#[test]
+fn invalid_config() {
+    let module = Units::try_from(Config {
+        families: vec![],
+        systems: vec![System::Metric],
+        max_val: 10,
+        halves: false,
+    });
+    assert_eq!(
+        "at least one unit family must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Units::try_from(Config {
+        families: vec![Family::Length],
+        systems: vec![],
+        max_val: 10,
+        halves: false,
+    });
+    assert_eq!(
+        "at least one system must be specified",
+        module.err().unwrap()
+    );
+
+    let module = Units::try_from(Config {
+        families: vec![Family::Length],
+        systems: vec![System::Metric],
+        max_val: 1,
+        halves: false,
+    });
+    assert_eq!("max_val must be at least 2", module.err().unwrap());
+
+    let module = Units::try_from(Config {
+        families: vec![Family::Length],
+        systems: vec![System::Metric],
+        max_val: 10_001,
+        halves: false,
+    });
+    assert_eq!("max_val cannot exceed 10000", module.err().unwrap());
+}
+
+#[test]
+fn presets() {
+    presets::units_1();
+    presets::units_2();
+}